@@ -0,0 +1,36 @@
+use game_of_life_core::{BrainState, BrainUniverse};
+
+const WIDTH: usize = 24;
+const HEIGHT: usize = 8;
+
+fn main() {
+    let mut universe = BrainUniverse::<WIDTH, HEIGHT>::new();
+
+    // A glider: two On cells followed by two Dying cells, heading right
+    universe.set_cell(3, 1, BrainState::Dying);
+    universe.set_cell(4, 1, BrainState::Dying);
+    universe.set_cell(3, 2, BrainState::On);
+    universe.set_cell(4, 2, BrainState::On);
+
+    for index in 0..10 {
+        print_generation(index, &universe);
+        universe.evolve();
+    }
+}
+
+/// Print the generation
+fn print_generation(index: usize, universe: &BrainUniverse<WIDTH, HEIGHT>) {
+    println!("Generation: {}", index);
+    for row in 0..HEIGHT {
+        for column in 0..WIDTH {
+            let symbol = match universe.state(row, column) {
+                BrainState::Off => "-",
+                BrainState::On => "*",
+                BrainState::Dying => "o",
+            };
+            print!("{} ", symbol);
+        }
+        println!();
+    }
+    println!();
+}
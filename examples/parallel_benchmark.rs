@@ -0,0 +1,41 @@
+use std::time::Instant;
+
+use game_of_life_core::{State, Universe};
+use rand::{Rng, SeedableRng};
+
+const SIZE: usize = 512;
+const STEPS: usize = 100;
+
+fn main() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+    let mut sequential = Box::new(Universe::<SIZE, SIZE>::new());
+    for row in 0..SIZE {
+        for column in 0..SIZE {
+            if rng.gen_bool(0.5) {
+                sequential.set_cell(row, column, State::Alive);
+            }
+        }
+    }
+    let mut parallel = sequential.clone();
+
+    let start = Instant::now();
+    for _ in 0..STEPS {
+        sequential.evolve();
+    }
+    let sequential_time = start.elapsed();
+
+    let start = Instant::now();
+    for _ in 0..STEPS {
+        parallel.evolve_parallel();
+    }
+    let parallel_time = start.elapsed();
+
+    assert!(*parallel == *sequential);
+    println!("{SIZE}x{SIZE}, {STEPS} steps");
+    println!("evolve():          {:?}", sequential_time);
+    println!("evolve_parallel(): {:?}", parallel_time);
+    println!(
+        "speedup: {:.2}x",
+        sequential_time.as_secs_f64() / parallel_time.as_secs_f64()
+    );
+}
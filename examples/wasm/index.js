@@ -0,0 +1,42 @@
+import init, { WasmUniverse } from "./pkg/game_of_life_wasm.js";
+
+const WIDTH = 128;
+const HEIGHT = 128;
+
+const { memory } = await init();
+const universe = new WasmUniverse(WIDTH, HEIGHT);
+for (let row = 0; row < HEIGHT; row++) {
+  for (let col = 0; col < WIDTH; col++) {
+    universe.set_cell(row, col, Math.random() < 0.3);
+  }
+}
+
+const canvas = document.getElementById("universe");
+canvas.width = WIDTH;
+canvas.height = HEIGHT;
+const context = canvas.getContext("2d");
+const image = context.createImageData(WIDTH, HEIGHT);
+const generation = document.getElementById("generation");
+
+function draw() {
+  // The buffer moves on every evolve(), so view it afresh each frame
+  const cells = new Uint8Array(memory.buffer, universe.cells_ptr(), WIDTH * HEIGHT);
+  for (let i = 0; i < cells.length; i++) {
+    const shade = cells[i] ? 0 : 255;
+    image.data[i * 4] = shade;
+    image.data[i * 4 + 1] = shade;
+    image.data[i * 4 + 2] = shade;
+    image.data[i * 4 + 3] = 255;
+  }
+  context.putImageData(image, 0, 0);
+  generation.textContent = `Generation: ${universe.generation()}`;
+}
+
+function tick() {
+  universe.evolve();
+  draw();
+  requestAnimationFrame(tick);
+}
+
+draw();
+requestAnimationFrame(tick);
@@ -0,0 +1,2 @@
+//! Builds `game_of_life_core::WasmUniverse` into a loadable wasm module
+pub use game_of_life_core::WasmUniverse;
@@ -0,0 +1,49 @@
+/*
+ * C API for game_of_life_core, built with the `c-ffi` feature.
+ *
+ * Every function taking a handle accepts either NULL, which is ignored, or a
+ * pointer returned by universe_new() that has not been freed.
+ */
+#ifndef GAME_OF_LIFE_CORE_H
+#define GAME_OF_LIFE_CORE_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* A universe owned by the caller, created by universe_new() */
+typedef struct OpaqueUniverse OpaqueUniverse;
+
+/* Creates an all-dead universe, or returns NULL if either dimension is zero */
+OpaqueUniverse *universe_new(uint32_t width, uint32_t height);
+
+/* Frees a universe created by universe_new() */
+void universe_free(OpaqueUniverse *u);
+
+/* Evolves the universe */
+void universe_evolve(OpaqueUniverse *u);
+
+/* Sets the cell alive or dead. Out-of-range coordinates are ignored. */
+void universe_set_cell(OpaqueUniverse *u, uint32_t row, uint32_t col, bool alive);
+
+/* Returns true if the cell is alive, false if it is dead or out of range */
+bool universe_is_alive(const OpaqueUniverse *u, uint32_t row, uint32_t col);
+
+/* Returns the number of times the universe has evolved, or 0 for NULL */
+uint64_t universe_generation(const OpaqueUniverse *u);
+
+/*
+ * Copies the cells into buf in row-major order, 1 for alive and 0 for dead.
+ * Returns 0 on success, or -1 if u or buf is NULL or len < width * height.
+ */
+int32_t universe_copy_to_buffer(const OpaqueUniverse *u, uint8_t *buf, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* GAME_OF_LIFE_CORE_H */
@@ -0,0 +1,216 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{Neighborhood, Universe};
+
+/// Digits of the extended Wechsler format, one per 5-cell column
+const DIGITS: &[u8; 32] = b"0123456789abcdefghijklmnopqrstuv";
+
+/// The digits after a `y`, which stand for runs of 4 to 39 blank columns
+const BLANK_RUN_DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// The most generations searched for the pattern to repeat
+const MAX_PERIOD: usize = 256;
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Returns the apgcode identifying the pattern, as used by apgsearch and
+    /// LifeWiki
+    ///
+    /// Still lifes are prefixed `xs` and their population, oscillators `xp`
+    /// and their period and spaceships `xq` and their period. The rest is the
+    /// extended Wechsler encoding of the phase and orientation with the
+    /// shortest, then alphabetically first, encoding. Patterns that do not
+    /// return to their current shape within 256 generations, including ones
+    /// that only settle into a cycle later, are prefixed `zz` and encode
+    /// their current phase.
+    ///
+    /// The pattern is followed through its bounding box, so it should stay
+    /// clear of wrapping edges.
+    pub fn to_apgcode(&self) -> String {
+        let (start, start_offset) = self.to_canonical_form();
+        let mut phases = Vec::from([start.alive_cells().collect::<Vec<_>>()]);
+        let mut universe = self.clone();
+        let mut prefix = None;
+        for period in 1..=MAX_PERIOD {
+            universe.evolve();
+            let (form, offset) = universe.to_canonical_form();
+            if form == start {
+                prefix = Some(match (period, offset == start_offset) {
+                    (1, true) => format!("xs{}", self.count_alive()),
+                    (_, true) => format!("xp{}", period),
+                    (_, false) => format!("xq{}", period),
+                });
+                break;
+            }
+            phases.push(form.alive_cells().collect());
+        }
+        let Some(prefix) = prefix else {
+            return format!("zz_{}", wechsler(&phases[0]));
+        };
+
+        let best = phases
+            .iter()
+            .flat_map(|cells| orientations(cells))
+            .map(|cells| wechsler(&cells))
+            .min_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)))
+            .unwrap_or_default();
+        format!("{}_{}", prefix, best)
+    }
+}
+
+/// Returns the cells in each of the eight orientations of the pattern, each
+/// moved so its bounding box starts at `(0, 0)`
+fn orientations(cells: &[(usize, usize)]) -> impl Iterator<Item = Vec<(usize, usize)>> + '_ {
+    (0..8).map(move |orientation| {
+        let moved: Vec<(isize, isize)> = cells
+            .iter()
+            .map(|&(row, column)| {
+                let (row, column) = (row as isize, column as isize);
+                let (row, column) = if orientation & 4 != 0 {
+                    (column, row)
+                } else {
+                    (row, column)
+                };
+                let row = if orientation & 1 != 0 { -row } else { row };
+                let column = if orientation & 2 != 0 {
+                    -column
+                } else {
+                    column
+                };
+                (row, column)
+            })
+            .collect();
+        let min_row = moved.iter().map(|&(row, _)| row).min().unwrap_or(0);
+        let min_col = moved.iter().map(|&(_, column)| column).min().unwrap_or(0);
+        moved
+            .into_iter()
+            .map(|(row, column)| ((row - min_row) as usize, (column - min_col) as usize))
+            .collect()
+    })
+}
+
+/// Encodes cells whose bounding box starts at `(0, 0)` in the extended
+/// Wechsler format
+///
+/// Each strip of five rows becomes one digit per column, with the top row
+/// as the lowest bit. Trailing blank columns are dropped, runs of blank
+/// columns are shortened with `w`, `x` and `y`, and strips are separated by
+/// `z`.
+fn wechsler(cells: &[(usize, usize)]) -> String {
+    if cells.is_empty() {
+        return String::from("0");
+    }
+    let height = cells.iter().map(|&(row, _)| row).max().unwrap_or(0) + 1;
+    let width = cells.iter().map(|&(_, column)| column).max().unwrap_or(0) + 1;
+    let mut columns = vec![0u8; height.div_ceil(5) * width];
+    for &(row, column) in cells {
+        columns[row / 5 * width + column] |= 1 << (row % 5);
+    }
+
+    let mut code = String::new();
+    for (strip, values) in columns.chunks(width).enumerate() {
+        if strip > 0 {
+            code.push('z');
+        }
+        let used = values
+            .iter()
+            .rposition(|&value| value != 0)
+            .map_or(0, |i| i + 1);
+        let mut blanks = 0;
+        for &value in &values[..used] {
+            if value == 0 {
+                blanks += 1;
+                continue;
+            }
+            push_blanks(&mut code, blanks);
+            blanks = 0;
+            code.push(DIGITS[value as usize] as char);
+        }
+    }
+    code
+}
+
+/// Appends the shortest encoding of a run of blank columns
+fn push_blanks(code: &mut String, mut blanks: usize) {
+    while blanks > 0 {
+        match blanks {
+            1 => code.push('0'),
+            2 => code.push('w'),
+            3 => code.push('x'),
+            _ => {
+                let run = blanks.min(39);
+                code.push('y');
+                code.push(BLANK_RUN_DIGITS[run - 4] as char);
+                blanks -= run;
+                continue;
+            }
+        }
+        return;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_still_life_codes() {
+        let mut block = Universe::<8, 8>::new();
+        block.seed_block(3, 3).unwrap();
+        assert_eq!(block.to_apgcode(), "xs4_33");
+
+        let beehive = Universe::<8, 8>::from([
+            [false, false, false, false, false, false, false, false],
+            [false, false, true, true, false, false, false, false],
+            [false, true, false, false, true, false, false, false],
+            [false, false, true, true, false, false, false, false],
+            [false; 8],
+            [false; 8],
+            [false; 8],
+            [false; 8],
+        ]);
+        assert_eq!(beehive.to_apgcode(), "xs6_696");
+        assert_eq!(Universe::<4, 4>::new().to_apgcode(), "xs0_0");
+    }
+
+    #[test]
+    fn test_oscillator_and_spaceship_codes() {
+        let mut blinker = Universe::<8, 8>::new();
+        blinker.seed_blinker(3, 2).unwrap();
+        assert_eq!(blinker.to_apgcode(), "xp2_7");
+
+        let mut glider = Universe::<16, 16>::new();
+        glider.seed_glider(4, 4).unwrap();
+        assert_eq!(glider.to_apgcode(), "xq4_153");
+        assert_eq!(glider.generation(), 0);
+    }
+
+    #[test]
+    fn test_wechsler_blank_runs() {
+        // Two cells six columns apart, and a cell below the first strip
+        assert_eq!(wechsler(&[(0, 0), (0, 6)]), "1y11");
+        assert_eq!(wechsler(&[(0, 0), (0, 4)]), "1x1");
+        assert_eq!(wechsler(&[(0, 0), (5, 1)]), "1z01");
+        assert_eq!(wechsler(&[(0, 1), (10, 0)]), "01zz1");
+        assert_eq!(wechsler(&[(0, 0), (0, 37)]), "1yw1");
+        assert_eq!(wechsler(&[(0, 0), (0, 40)]), "1yz1");
+        assert_eq!(wechsler(&[(0, 0), (0, 41)]), "1yz01");
+    }
+
+    #[test]
+    fn test_wide_gap_apgcode() {
+        let mut universe = Universe::<64, 8>::new();
+        universe.seed_block(2, 2).unwrap();
+        universe.seed_block(2, 40).unwrap();
+        assert_eq!(universe.to_apgcode(), "xs8_33yw33");
+    }
+
+    #[test]
+    fn test_dying_pattern_is_unidentified() {
+        let mut universe = Universe::<8, 8>::new();
+        universe.set_cells_alive(&[(2, 2), (2, 3)]).unwrap();
+        assert_eq!(universe.to_apgcode(), "zz_11");
+    }
+}
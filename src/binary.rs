@@ -0,0 +1,165 @@
+use core::fmt;
+
+use crate::{Neighborhood, State, Universe};
+
+/// The magic bytes that start every encoded universe
+const MAGIC: [u8; 4] = *b"CGOL";
+/// The length of the magic bytes and the generation
+const HEADER_LEN: usize = 8;
+
+/// Returns the number of bytes [`Universe::to_bytes`] writes for a universe
+/// of the given size
+///
+/// An 8-byte header is followed by one bit per cell.
+pub const fn universe_byte_size(width: usize, height: usize) -> usize {
+    HEADER_LEN + (width * height).div_ceil(8)
+}
+
+/// Errors returned when a universe cannot be decoded from bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum BinaryDeserializeError {
+    /// The buffer does not start with `b"CGOL"`
+    InvalidMagic,
+    /// The buffer is shorter than a universe of the requested size needs
+    BufferTooShort { expected: usize, got: usize },
+}
+
+impl fmt::Display for BinaryDeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryDeserializeError::InvalidMagic => write!(f, "missing CGOL magic bytes"),
+            BinaryDeserializeError::BufferTooShort { expected, got } => {
+                write!(f, "expected at least {} bytes, found {}", expected, got)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BinaryDeserializeError {}
+
+/// Checks at compile time that a byte count fits a universe
+struct ByteSize<const W: usize, const H: usize, const BYTES: usize>;
+
+impl<const W: usize, const H: usize, const BYTES: usize> ByteSize<W, H, BYTES> {
+    const CHECK: () = assert!(
+        BYTES == universe_byte_size(W, H),
+        "BYTES must equal universe_byte_size(W, H)"
+    );
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Encodes the cell states and generation in a compact binary format
+    ///
+    /// The bytes are `b"CGOL"`, the generation as a little-endian `u32`,
+    /// saturating at `u32::MAX`, then one bit per cell in row-major order,
+    /// most significant bit first. Stable Rust cannot compute the array length
+    /// from `W` and `H`, so `BYTES` must equal `universe_byte_size(W, H)`:
+    ///
+    /// ```
+    /// use game_of_life_core::{universe_byte_size, Universe};
+    ///
+    /// let bytes = Universe::<10, 10>::new().to_bytes::<{ universe_byte_size(10, 10) }>();
+    /// assert_eq!(bytes.len(), 21);
+    /// ```
+    pub fn to_bytes<const BYTES: usize>(&self) -> [u8; BYTES] {
+        let () = ByteSize::<W, H, BYTES>::CHECK;
+        let mut bytes = [0; BYTES];
+        bytes[..4].copy_from_slice(&MAGIC);
+        let generation = u32::try_from(self.generation).unwrap_or(u32::MAX);
+        bytes[4..HEADER_LEN].copy_from_slice(&generation.to_le_bytes());
+        for (index, cell) in self.grid.iter().flatten().enumerate() {
+            if cell.is_alive() {
+                bytes[HEADER_LEN + index / 8] |= 0x80 >> (index % 8);
+            }
+        }
+        bytes
+    }
+
+    /// Decodes a universe written by [`to_bytes`](Self::to_bytes), with the
+    /// default rule and boundary
+    ///
+    /// Bytes past the end of the cells are ignored.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BinaryDeserializeError> {
+        let expected = universe_byte_size(W, H);
+        if bytes.len() < expected {
+            return Err(BinaryDeserializeError::BufferTooShort {
+                expected,
+                got: bytes.len(),
+            });
+        }
+        if bytes[..4] != MAGIC {
+            return Err(BinaryDeserializeError::InvalidMagic);
+        }
+
+        let mut universe = Self::new();
+        let mut generation = [0; 4];
+        generation.copy_from_slice(&bytes[4..HEADER_LEN]);
+        universe.generation = u32::from_le_bytes(generation) as u64;
+        for (index, cell) in universe.grid.iter_mut().flatten().enumerate() {
+            if bytes[HEADER_LEN + index / 8] & (0x80 >> (index % 8)) != 0 {
+                cell.set_state(State::Alive);
+            }
+        }
+        Ok(universe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BYTES: usize = universe_byte_size(10, 10);
+
+    fn round_trip(universe: &Universe<10, 10>) {
+        let bytes = universe.to_bytes::<BYTES>();
+        let decoded = Universe::<10, 10>::from_bytes(&bytes).unwrap();
+        assert_eq!(&decoded, universe);
+        assert_eq!(decoded.generation(), universe.generation());
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let mut glider = Universe::<10, 10>::new();
+        glider.seed_glider(3, 4).unwrap();
+        glider.step_n(3);
+        round_trip(&glider);
+
+        let mut block = Universe::<10, 10>::new();
+        block.seed_block(8, 8).unwrap();
+        round_trip(&block);
+        round_trip(&Universe::new());
+    }
+
+    #[test]
+    fn test_layout() {
+        let bytes = Universe::<10, 10>::new().to_bytes::<BYTES>();
+        assert_eq!(&bytes[..HEADER_LEN], b"CGOL\0\0\0\0");
+        assert!(bytes[HEADER_LEN..].iter().all(|&byte| byte == 0));
+
+        let mut universe = Universe::<10, 10>::new();
+        universe.set_cells_alive(&[(0, 0), (0, 9), (9, 9)]).unwrap();
+        let bytes = universe.to_bytes::<BYTES>();
+        // Cell 9 is the second bit of the second byte, cell 99 the fourth of the last
+        assert_eq!(bytes[HEADER_LEN..HEADER_LEN + 2], [0x80, 0x40]);
+        assert_eq!(bytes[BYTES - 1], 0x10);
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let mut bytes = Universe::<10, 10>::new().to_bytes::<BYTES>();
+        assert_eq!(
+            Universe::<10, 10>::from_bytes(&bytes[..BYTES - 1]),
+            Err(BinaryDeserializeError::BufferTooShort {
+                expected: BYTES,
+                got: BYTES - 1,
+            })
+        );
+        bytes[0] = b'X';
+        assert_eq!(
+            Universe::<10, 10>::from_bytes(&bytes),
+            Err(BinaryDeserializeError::InvalidMagic)
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use crate::{State, Universe};
+
+/// Returns the `WORDS` parameter of a `BitUniverse<W, H, WORDS>`
+///
+/// Each row starts on a word boundary, so a row takes `(W + 63) / 64` words.
+pub const fn bit_universe_words(width: usize, height: usize) -> usize {
+    height * width.div_ceil(64)
+}
+
+/// A universe storing one bit per cell
+///
+/// Rows are packed into `u64` words, column `c` of a row being bit `c % 64`
+/// of word `c / 64`. Stable Rust cannot compute array lengths from `W` and
+/// `H`, so the word count is a third parameter that must equal
+/// `bit_universe_words(W, H)`. Both checks, and that `W` and `H` are
+/// non-zero, happen at compile time:
+///
+/// ```
+/// use game_of_life_core::{bit_universe_words, BitUniverse};
+///
+/// let universe = BitUniverse::<100, 50, { bit_universe_words(100, 50) }>::new();
+/// assert_eq!(universe.count_alive(), 0);
+/// ```
+///
+/// The universe evolves by Conway's rule on a torus, computing 64 cells at a
+/// time with bitwise adders. A 1024x1024 grid takes 128 KB, a thirty-second of
+/// the dense `Universe`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitUniverse<const W: usize, const H: usize, const WORDS: usize> {
+    words: [u64; WORDS],
+}
+
+impl<const W: usize, const H: usize, const WORDS: usize> BitUniverse<W, H, WORDS> {
+    const WORDS_PER_ROW: usize = W.div_ceil(64);
+    const CHECK_WORDS: () = assert!(
+        WORDS == bit_universe_words(W, H),
+        "WORDS must equal bit_universe_words(W, H)"
+    );
+    // `evolve` wraps around the edges through `W - 1` and `H - 1`
+    const CHECK_DIMENSIONS: () = assert!(W > 0 && H > 0, "W and H must be non-zero");
+
+    /// Creates an all-dead universe
+    pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CHECK_WORDS;
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CHECK_DIMENSIONS;
+        BitUniverse { words: [0; WORDS] }
+    }
+
+    /// Sets the state of the cell
+    pub fn set_cell(&mut self, row: usize, column: usize, state: State) {
+        let (index, mask) = Self::locate(row, column);
+        match state {
+            State::Alive => self.words[index] |= mask,
+            State::Dead => self.words[index] &= !mask,
+        }
+    }
+
+    /// Returns true if the cell at the given position is alive
+    pub fn is_alive_at(&self, row: usize, column: usize) -> bool {
+        let (index, mask) = Self::locate(row, column);
+        self.words[index] & mask != 0
+    }
+
+    /// Returns the number of alive cells
+    pub fn count_alive(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Evolves the universe
+    ///
+    /// The words are updated in place one column of words at a time, left to
+    /// right. Only single bits of the neighboring columns are needed, so the
+    /// old carry bits are saved before they are overwritten: the first and
+    /// last cell of each row for the wrap, and the top bit of each word in
+    /// the column to the west.
+    pub fn evolve(&mut self) {
+        let last_mask = match W % 64 {
+            0 => u64::MAX,
+            bits => (1 << bits) - 1,
+        };
+        let mut west = [false; H];
+        let mut first = [false; H];
+        for row in 0..H {
+            west[row] = self.bit(row, W - 1) != 0;
+            first[row] = self.bit(row, 0) != 0;
+        }
+        let mut next_west = [false; H];
+        for word in 0..Self::WORDS_PER_ROW {
+            let top = self.word(0, word);
+            let (mut above_row, mut above) = (H - 1, self.word(H - 1, word));
+            let mut center = top;
+            for row in 0..H {
+                let below_row = (row + 1) % H;
+                let below = if below_row == 0 {
+                    top
+                } else {
+                    self.word(below_row, word)
+                };
+                let neighbors = [
+                    shift_west(above, west[above_row]),
+                    above,
+                    shift_east(above, self.east_carry(above_row, word, &first)),
+                    shift_west(center, west[row]),
+                    shift_east(center, self.east_carry(row, word, &first)),
+                    shift_west(below, west[below_row]),
+                    below,
+                    shift_east(below, self.east_carry(below_row, word, &first)),
+                ];
+                let mut next = next_word(center, neighbors);
+                if word + 1 == Self::WORDS_PER_ROW {
+                    next &= last_mask;
+                }
+                next_west[row] = center >> 63 != 0;
+                self.words[row * Self::WORDS_PER_ROW + word] = next;
+                (above_row, above) = (row, center);
+                center = below;
+            }
+            west = next_west;
+        }
+    }
+
+    /// Returns the word index and bit mask of a cell
+    fn locate(row: usize, column: usize) -> (usize, u64) {
+        assert!(
+            row < H && column < W,
+            "cell ({}, {}) is out of bounds for a {}x{} universe",
+            row,
+            column,
+            W,
+            H
+        );
+        (row * Self::WORDS_PER_ROW + column / 64, 1 << (column % 64))
+    }
+
+    fn word(&self, row: usize, word: usize) -> u64 {
+        self.words[row * Self::WORDS_PER_ROW + word]
+    }
+
+    fn bit(&self, row: usize, column: usize) -> u64 {
+        (self.word(row, column / 64) >> (column % 64)) & 1
+    }
+
+    /// Returns the cell east of the last bit of a word, placed at that bit
+    ///
+    /// Words to the east are not updated yet, and the first word of each row
+    /// is read from `first` because it is updated before the last.
+    fn east_carry(&self, row: usize, word: usize, first: &[bool; H]) -> u64 {
+        if word + 1 == Self::WORDS_PER_ROW {
+            (first[row] as u64) << ((W - 1) % 64)
+        } else {
+            self.word(row, word + 1) << 63
+        }
+    }
+}
+
+/// Returns a word whose bit `c` is the cell at column `c - 1`, given the cell
+/// west of bit 0
+fn shift_west(word: u64, carry: bool) -> u64 {
+    (word << 1) | carry as u64
+}
+
+/// Returns a word whose bit `c` is the cell at column `c + 1`, given the cell
+/// east of the last bit already in place
+fn shift_east(word: u64, carry: u64) -> u64 {
+    (word >> 1) | carry
+}
+
+impl<const W: usize, const H: usize, const WORDS: usize> Default for BitUniverse<W, H, WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const W: usize, const H: usize, const WORDS: usize> From<&Universe<W, H>>
+    for BitUniverse<W, H, WORDS>
+{
+    fn from(universe: &Universe<W, H>) -> Self {
+        let mut bits = BitUniverse::new();
+        for (row, column) in universe.alive_cells() {
+            bits.set_cell(row, column, State::Alive);
+        }
+        bits
+    }
+}
+
+/// Returns `(sum, carry)` of three one-bit values in each bit position
+fn full_add(a: u64, b: u64, c: u64) -> (u64, u64) {
+    (a ^ b ^ c, (a & b) | (c & (a ^ b)))
+}
+
+/// Applies B3/S23 to 64 cells at once, given their eight neighbor words
+fn next_word(center: u64, [n0, n1, n2, n3, n4, n5, n6, n7]: [u64; 8]) -> u64 {
+    // Sum the neighbors into the binary digits ones, twos, fours and eights
+    let (sum_a, carry_a) = full_add(n0, n1, n2);
+    let (sum_b, carry_b) = full_add(n3, n4, n5);
+    let (sum_c, carry_c) = (n6 ^ n7, n6 & n7);
+    let (ones, carry_d) = full_add(sum_a, sum_b, sum_c);
+    let (twos_sum, fours_a) = full_add(carry_a, carry_b, carry_c);
+    let (twos, fours_b) = (twos_sum ^ carry_d, twos_sum & carry_d);
+    let fours_or_eights = fours_a | fours_b;
+    // Born with 3 neighbors, survives with 2 or 3
+    twos & !fours_or_eights & (ones | center)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+
+    fn assert_same<const W: usize, const H: usize, const WORDS: usize>(
+        bits: &BitUniverse<W, H, WORDS>,
+        dense: &Universe<W, H>,
+    ) {
+        for row in 0..H {
+            for column in 0..W {
+                assert_eq!(
+                    bits.is_alive_at(row, column),
+                    dense.is_alive(row, column),
+                    "cell ({}, {})",
+                    row,
+                    column
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_words() {
+        assert_eq!(bit_universe_words(64, 3), 3);
+        assert_eq!(bit_universe_words(65, 3), 6);
+        assert_eq!(bit_universe_words(1, 1), 1);
+    }
+
+    #[test]
+    fn test_set_cell() {
+        let mut universe = BitUniverse::<70, 2, 4>::new();
+        universe.set_cell(1, 65, State::Alive);
+        universe.set_cell(0, 0, State::Alive);
+        assert!(universe.is_alive_at(1, 65));
+        assert!(!universe.is_alive_at(0, 65));
+        assert_eq!(universe.count_alive(), 2);
+        universe.set_cell(1, 65, State::Dead);
+        assert_eq!(universe.count_alive(), 1);
+    }
+
+    #[test]
+    fn test_glider_matches_universe() {
+        let mut dense = Universe::<10, 10>::new();
+        dense.seed_glider(0, 0).unwrap();
+        let mut bits = BitUniverse::<10, 10, { bit_universe_words(10, 10) }>::from(&dense);
+        for _ in 0..20 {
+            dense.evolve();
+            bits.evolve();
+            assert_same(&bits, &dense);
+        }
+        assert_eq!(bits.count_alive(), 5);
+    }
+
+    #[test]
+    fn test_random_matches_universe_across_words() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(33);
+        let mut dense = Universe::<130, 12>::new();
+        for row in 0..12 {
+            for column in 0..130 {
+                if rng.gen_bool(0.4) {
+                    dense.set_cell(row, column, State::Alive);
+                }
+            }
+        }
+        let mut bits = BitUniverse::<130, 12, { bit_universe_words(130, 12) }>::from(&dense);
+        for _ in 0..30 {
+            dense.evolve();
+            bits.evolve();
+        }
+        assert_same(&bits, &dense);
+    }
+
+    #[test]
+    fn test_single_word_rows() {
+        // A glider on a 64-wide torus crosses the word boundary through the wrap
+        let mut dense = Universe::<64, 6>::new();
+        dense.seed_glider(1, 61).unwrap();
+        let mut bits = BitUniverse::<64, 6, { bit_universe_words(64, 6) }>::from(&dense);
+        for _ in 0..12 {
+            dense.evolve();
+            bits.evolve();
+        }
+        assert_same(&bits, &dense);
+    }
+
+    fn assert_random_matches<const W: usize, const H: usize, const WORDS: usize>(seed: u64) {
+        let mut dense = Universe::<W, H>::from_seed_deterministic(seed, 100);
+        let mut bits = BitUniverse::<W, H, WORDS>::from(&dense);
+        for _ in 0..10 {
+            dense.evolve();
+            bits.evolve();
+            assert_same(&bits, &dense);
+        }
+    }
+
+    #[test]
+    fn test_in_place_evolve_at_edges() {
+        // Shapes where the rows above and below, or the words east and west,
+        // wrap onto the same word
+        assert_random_matches::<130, 1, { bit_universe_words(130, 1) }>(1);
+        assert_random_matches::<130, 2, { bit_universe_words(130, 2) }>(2);
+        assert_random_matches::<1, 7, { bit_universe_words(1, 7) }>(3);
+        assert_random_matches::<128, 3, { bit_universe_words(128, 3) }>(4);
+    }
+
+    #[test]
+    fn test_memory_footprint() {
+        let bits =
+            core::mem::size_of::<BitUniverse<1024, 1024, { bit_universe_words(1024, 1024) }>>();
+        let dense = core::mem::size_of::<Universe<1024, 1024>>();
+        assert_eq!(bits, 128 * 1024);
+        assert!(dense >= 32 * bits);
+    }
+}
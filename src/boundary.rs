@@ -0,0 +1,285 @@
+use crate::{MooreNeighborhood, Neighborhood};
+
+/// How neighbors beyond the edges of the grid are treated
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BoundaryCondition {
+    /// The edges wrap around, so the universe is a torus
+    #[default]
+    Toroidal,
+    /// Cells beyond the edges are permanently dead
+    FixedDead,
+    /// Cells beyond the edges mirror the nearest edge cell, e.g. column -1
+    /// mirrors column 0 and column W mirrors column W-1
+    Reflective,
+    /// Each axis either wraps around or is bordered by dead cells
+    ///
+    /// `wrap_rows` joins the top and bottom edges and `wrap_cols` joins the
+    /// left and right edges, so wrapping only columns makes a vertical tube.
+    Cylindrical { wrap_rows: bool, wrap_cols: bool },
+}
+
+/// How a single axis treats indices beyond its ends
+#[derive(Clone, Copy)]
+enum Edge {
+    Wrap,
+    Dead,
+    Mirror,
+}
+
+impl Edge {
+    fn wrapping(wraps: bool) -> Self {
+        if wraps {
+            Edge::Wrap
+        } else {
+            Edge::Dead
+        }
+    }
+
+    fn offset(self, index: usize, delta: isize, len: usize) -> Option<usize> {
+        let target = index as isize + delta;
+        match self {
+            Edge::Wrap => Some(target.rem_euclid(len as isize) as usize),
+            Edge::Dead => (0..len as isize)
+                .contains(&target)
+                .then_some(target as usize),
+            Edge::Mirror => Some(target.clamp(0, len as isize - 1) as usize),
+        }
+    }
+}
+
+impl BoundaryCondition {
+    /// Returns the row `delta` steps away from `row` in a grid of `height`
+    /// rows, or `None` if that neighbor does not exist
+    pub(crate) fn row_offset(self, row: usize, delta: isize, height: usize) -> Option<usize> {
+        self.edges().0.offset(row, delta, height)
+    }
+
+    /// Returns the column `delta` steps away from `column` in a grid of
+    /// `width` columns, or `None` if that neighbor does not exist
+    pub(crate) fn col_offset(self, column: usize, delta: isize, width: usize) -> Option<usize> {
+        self.edges().1.offset(column, delta, width)
+    }
+
+    /// Calls `f(row, column)` for each of the eight Moore neighbors of a cell
+    /// in a grid of `height` rows and `width` columns that exists under the
+    /// boundary condition
+    ///
+    /// For the automata that do not go through `Universe` and its
+    /// `Neighborhood` parameter.
+    pub(crate) fn for_each_moore_neighbor<F: FnMut(usize, usize)>(
+        self,
+        row: usize,
+        column: usize,
+        height: usize,
+        width: usize,
+        mut f: F,
+    ) {
+        MooreNeighborhood::for_each_offset(|delta_row, delta_col| {
+            if let (Some(neighbor_row), Some(neighbor_col)) = (
+                self.row_offset(row, delta_row, height),
+                self.col_offset(column, delta_col, width),
+            ) {
+                f(neighbor_row, neighbor_col);
+            }
+        });
+    }
+
+    /// Returns the boundary condition with the row and the column axes swapped,
+    /// for transforms that turn rows into columns
+    pub(crate) fn transposed(self) -> Self {
+        match self {
+            BoundaryCondition::Cylindrical {
+                wrap_rows,
+                wrap_cols,
+            } => BoundaryCondition::Cylindrical {
+                wrap_rows: wrap_cols,
+                wrap_cols: wrap_rows,
+            },
+            boundary => boundary,
+        }
+    }
+
+    /// Returns whether the row and the column axes wrap around
+    pub(crate) fn wraps(self) -> (bool, bool) {
+        let (rows, cols) = self.edges();
+        (matches!(rows, Edge::Wrap), matches!(cols, Edge::Wrap))
+    }
+
+    /// Returns how the row and the column axes treat their ends
+    fn edges(self) -> (Edge, Edge) {
+        match self {
+            BoundaryCondition::Toroidal => (Edge::Wrap, Edge::Wrap),
+            BoundaryCondition::FixedDead => (Edge::Dead, Edge::Dead),
+            BoundaryCondition::Reflective => (Edge::Mirror, Edge::Mirror),
+            BoundaryCondition::Cylindrical {
+                wrap_rows,
+                wrap_cols,
+            } => (Edge::wrapping(wrap_rows), Edge::wrapping(wrap_cols)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{State, Universe};
+
+    /// Glider heading towards the top-left corner
+    fn seed_glider<const W: usize, const H: usize>(universe: &mut Universe<W, H>) {
+        for &(row, column) in [(1, 1), (1, 2), (1, 3), (2, 1), (3, 2)].iter() {
+            universe.set_cell(row, column, State::Alive);
+        }
+    }
+
+    #[test]
+    fn test_offset() {
+        assert_eq!(BoundaryCondition::Toroidal.row_offset(0, -1, 5), Some(4));
+        assert_eq!(BoundaryCondition::Toroidal.row_offset(4, 1, 5), Some(0));
+        assert_eq!(BoundaryCondition::FixedDead.row_offset(0, -1, 5), None);
+        assert_eq!(BoundaryCondition::FixedDead.row_offset(4, 1, 5), None);
+        assert_eq!(BoundaryCondition::FixedDead.row_offset(2, 1, 5), Some(3));
+        assert_eq!(BoundaryCondition::Reflective.col_offset(0, -1, 5), Some(0));
+        assert_eq!(BoundaryCondition::Reflective.col_offset(4, 1, 5), Some(4));
+        assert_eq!(BoundaryCondition::Reflective.col_offset(2, -1, 5), Some(1));
+    }
+
+    #[test]
+    fn test_corner_neighbors_per_boundary() {
+        let alive = [(0, 0), (0, 1), (0, 3), (1, 0), (3, 0), (3, 3)];
+        let count_at = |boundary, row, column| {
+            let mut universe = Universe::<4, 4>::with_boundary(boundary);
+            for &(r, c) in alive.iter() {
+                universe.set_cell(r, c, State::Alive);
+            }
+            universe.live_neighbor_count(row, column)
+        };
+
+        // Wraps to (3, 3), (3, 0), (0, 3), (0, 1) and (1, 0)
+        assert_eq!(count_at(BoundaryCondition::Toroidal, 0, 0), 5);
+        // Only (0, 1) and (1, 0) exist
+        assert_eq!(count_at(BoundaryCondition::FixedDead, 0, 0), 2);
+        // (-1, -1), (-1, 0) and (0, -1) mirror the corner itself, (-1, 1)
+        // mirrors (0, 1) and (1, -1) mirrors (1, 0)
+        assert_eq!(count_at(BoundaryCondition::Reflective, 0, 0), 7);
+    }
+
+    #[test]
+    fn test_edge_neighbors_under_reflective() {
+        let mut universe = Universe::<4, 4>::with_boundary(BoundaryCondition::Reflective);
+        for &(row, column) in [(0, 1), (0, 3), (2, 2)].iter() {
+            universe.set_cell(row, column, State::Alive);
+        }
+        // Row -1 mirrors row 0, so (0, 1) and (0, 3) are each seen twice
+        assert_eq!(universe.live_neighbor_count(0, 2), 4);
+        // Column 4 mirrors column 3, which holds (0, 3) itself
+        assert_eq!(universe.live_neighbor_count(0, 3), 3);
+        assert_eq!(universe.live_neighbor_count(1, 2), 3);
+    }
+
+    #[test]
+    fn test_corner_neighbors_under_fixed_dead() {
+        let mut universe = Universe::<4, 4>::with_boundary(BoundaryCondition::FixedDead);
+        universe.fill(State::Alive);
+        assert_eq!(universe.live_neighbor_count(0, 0), 3);
+        assert_eq!(universe.live_neighbor_count(3, 3), 3);
+        assert_eq!(universe.live_neighbor_count(0, 2), 5);
+        assert_eq!(universe.live_neighbor_count(1, 1), 8);
+
+        let mut universe = Universe::<4, 4>::with_boundary(BoundaryCondition::Toroidal);
+        universe.fill(State::Alive);
+        assert_eq!(universe.live_neighbor_count(0, 0), 8);
+    }
+
+    #[test]
+    fn test_glider_wraps_under_toroidal() {
+        let mut universe = Universe::<10, 10>::with_boundary(BoundaryCondition::Toroidal);
+        seed_glider(&mut universe);
+        for _ in 0..8 {
+            universe.evolve();
+            assert_eq!(universe.count_alive(), 5);
+        }
+        // The glider has moved two cells up-left, across both edges
+        let expected = [(0, 9), (1, 0), (9, 0), (9, 1), (9, 9)];
+        assert!(universe.alive_cells().eq(expected.into_iter()));
+    }
+
+    #[test]
+    fn test_glider_collapses_under_fixed_dead() {
+        let mut universe = Universe::<10, 10>::with_boundary(BoundaryCondition::FixedDead);
+        seed_glider(&mut universe);
+        for _ in 0..40 {
+            universe.evolve();
+        }
+        // The glider cannot cross the corner and decays into a block
+        assert_eq!(universe.count_alive(), 4);
+        assert!(universe.is_stable());
+        assert!(universe
+            .alive_cells()
+            .all(|(row, column)| row < 3 && column < 3));
+    }
+
+    #[test]
+    fn test_cylindrical_offsets() {
+        let tube = BoundaryCondition::Cylindrical {
+            wrap_rows: false,
+            wrap_cols: true,
+        };
+        assert_eq!(tube.row_offset(0, -1, 5), None);
+        assert_eq!(tube.row_offset(2, 1, 5), Some(3));
+        assert_eq!(tube.col_offset(0, -1, 5), Some(4));
+        assert_eq!(tube.col_offset(4, 1, 5), Some(0));
+    }
+
+    #[test]
+    fn test_glider_dies_at_dead_bottom_edge() {
+        let mut universe = Universe::<10, 10>::new();
+        universe.set_wrapping(false, true);
+        universe.seed_glider(5, 2).unwrap();
+        for _ in 0..40 {
+            universe.evolve();
+        }
+        // The glider cannot cross the bottom edge and decays into a block
+        assert_eq!(universe.count_alive(), 4);
+        assert!(universe.is_stable());
+        assert!(universe.alive_cells().all(|(row, _)| row >= 8));
+    }
+
+    #[test]
+    fn test_glider_wraps_across_columns() {
+        let mut universe = Universe::<10, 12>::new();
+        universe.set_wrapping(false, true);
+        universe.seed_glider(0, 6).unwrap();
+        for _ in 0..20 {
+            universe.evolve();
+        }
+        // Five cells down and right from column 6, so across the right edge
+        let mut expected = Universe::<10, 12>::new();
+        expected
+            .set_cells_alive(&[(5, 2), (6, 3), (7, 1), (7, 2), (7, 3)])
+            .unwrap();
+        assert_eq!(universe, expected);
+    }
+
+    #[test]
+    fn test_no_wrapping_matches_fixed_dead() {
+        let mut cylinder = Universe::<10, 10>::new();
+        cylinder.set_wrapping(false, false);
+        let mut fixed = Universe::<10, 10>::with_boundary(BoundaryCondition::FixedDead);
+        seed_glider(&mut cylinder);
+        seed_glider(&mut fixed);
+        for _ in 0..40 {
+            cylinder.evolve();
+            fixed.evolve();
+            assert_eq!(cylinder, fixed);
+        }
+
+        let mut torus = Universe::<10, 10>::new();
+        torus.set_wrapping(true, true);
+        seed_glider(&mut torus);
+        for _ in 0..8 {
+            torus.evolve();
+        }
+        let expected = [(0, 9), (1, 0), (9, 0), (9, 1), (9, 9)];
+        assert!(torus.alive_cells().eq(expected.into_iter()));
+    }
+}
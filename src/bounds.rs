@@ -0,0 +1,203 @@
+use crate::{Neighborhood, Universe};
+
+/// The smallest rectangle enclosing every alive cell, with inclusive bounds
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BoundingBox {
+    pub min_row: usize,
+    pub max_row: usize,
+    pub min_col: usize,
+    pub max_col: usize,
+}
+
+impl BoundingBox {
+    /// Returns the number of columns covered by the box
+    pub fn width(&self) -> usize {
+        self.max_col - self.min_col + 1
+    }
+
+    /// Returns the number of rows covered by the box
+    pub fn height(&self) -> usize {
+        self.max_row - self.min_row + 1
+    }
+
+    /// Returns the `(row, column)` center of the box
+    pub fn center(&self) -> (f32, f32) {
+        (
+            (self.min_row + self.max_row) as f32 / 2.0,
+            (self.min_col + self.max_col) as f32 / 2.0,
+        )
+    }
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Returns the smallest rectangle enclosing every alive cell, or `None`
+    /// if the universe is empty
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        let mut bounds: Option<BoundingBox> = None;
+        for (row, column) in self.alive_cells() {
+            let bounds = bounds.get_or_insert(BoundingBox {
+                min_row: row,
+                max_row: row,
+                min_col: column,
+                max_col: column,
+            });
+            // Rows arrive in order, so only the last row and the columns move
+            bounds.max_row = row;
+            bounds.min_col = bounds.min_col.min(column);
+            bounds.max_col = bounds.max_col.max(column);
+        }
+        bounds
+    }
+
+    /// Returns the number of columns spanned by the alive cells
+    pub fn pattern_width(&self) -> usize {
+        self.bounding_box().map_or(0, |bounds| bounds.width())
+    }
+
+    /// Returns the number of rows spanned by the alive cells
+    pub fn pattern_height(&self) -> usize {
+        self.bounding_box().map_or(0, |bounds| bounds.height())
+    }
+
+    /// Returns the `(row, column)` center of the bounding box, or `None` if
+    /// the universe is empty
+    pub fn pattern_center(&self) -> Option<(f32, f32)> {
+        self.bounding_box().map(|bounds| bounds.center())
+    }
+
+    /// Returns the mean `(row, column)` of the alive cells, or `None` if the
+    /// universe is empty
+    ///
+    /// Unlike [`pattern_center`](Self::pattern_center) every alive cell is
+    /// weighted equally.
+    pub fn center_of_mass(&self) -> Option<(f32, f32)> {
+        let (mut count, mut rows, mut cols) = (0usize, 0usize, 0usize);
+        self.for_each_alive(|row, column| {
+            count += 1;
+            rows += row;
+            cols += column;
+        });
+        (count > 0).then(|| (rows as f32 / count as f32, cols as f32 / count as f32))
+    }
+
+    /// Returns the sum of the squared distances of the alive cells from their
+    /// center of mass, or 0.0 if the universe is empty
+    pub fn moment_of_inertia(&self) -> f32 {
+        let Some((center_row, center_col)) = self.center_of_mass() else {
+            return 0.0;
+        };
+        let mut moment = 0.0;
+        self.for_each_alive(|row, column| {
+            let (dr, dc) = (row as f32 - center_row, column as f32 - center_col);
+            moment += dr * dr + dc * dc;
+        });
+        moment
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::State;
+
+    #[test]
+    fn test_bounding_box_glider() {
+        let mut universe = Universe::<8, 8>::new();
+        universe.seed_glider(2, 3).unwrap();
+        assert_eq!(
+            universe.bounding_box(),
+            Some(BoundingBox {
+                min_row: 2,
+                max_row: 4,
+                min_col: 3,
+                max_col: 5,
+            })
+        );
+        assert_eq!(universe.pattern_center(), Some((3.0, 4.0)));
+    }
+
+    #[test]
+    fn test_bounding_box_block() {
+        let mut universe = Universe::<6, 6>::new();
+        universe.seed_block(4, 1).unwrap();
+        assert_eq!(universe.pattern_width(), 2);
+        assert_eq!(universe.pattern_height(), 2);
+        assert_eq!(universe.pattern_center(), Some((4.5, 1.5)));
+    }
+
+    #[test]
+    fn test_bounding_box_empty() {
+        let universe = Universe::<6, 6>::new();
+        assert_eq!(universe.bounding_box(), None);
+        assert_eq!(universe.pattern_width(), 0);
+        assert_eq!(universe.pattern_height(), 0);
+        assert_eq!(universe.pattern_center(), None);
+    }
+
+    #[test]
+    fn test_bounding_box_single_cell() {
+        let mut universe = Universe::<8, 8>::new();
+        universe.set_cell(3, 5, State::Alive);
+        assert_eq!(
+            universe.bounding_box(),
+            Some(BoundingBox {
+                min_row: 3,
+                max_row: 3,
+                min_col: 5,
+                max_col: 5,
+            })
+        );
+    }
+
+    #[test]
+    fn test_bounding_box_uneven_rows() {
+        let mut universe = Universe::<8, 8>::new();
+        universe.set_cell(1, 6, State::Alive);
+        universe.set_cell(5, 0, State::Alive);
+        universe.set_cell(3, 7, State::Alive);
+        let bounds = universe.bounding_box().unwrap();
+        assert_eq!((bounds.min_row, bounds.max_row), (1, 5));
+        assert_eq!((bounds.min_col, bounds.max_col), (0, 7));
+        assert_eq!((bounds.width(), bounds.height()), (8, 5));
+    }
+
+    #[test]
+    fn test_center_of_mass() {
+        let mut universe = Universe::<6, 6>::new();
+        assert_eq!(universe.center_of_mass(), None);
+        assert_eq!(universe.moment_of_inertia(), 0.0);
+
+        universe.set_cell(2, 3, State::Alive);
+        assert_eq!(universe.center_of_mass(), Some((2.0, 3.0)));
+        assert_eq!(universe.moment_of_inertia(), 0.0);
+
+        universe.clear();
+        universe.seed_block(1, 1).unwrap();
+        assert_eq!(universe.center_of_mass(), Some((1.5, 1.5)));
+        assert_eq!(universe.moment_of_inertia(), 2.0);
+
+        let mut blinker = Universe::<5, 5>::new();
+        blinker.seed_blinker(2, 1).unwrap();
+        assert_eq!(blinker.center_of_mass(), Some((2.0, 2.0)));
+        assert_eq!(blinker.moment_of_inertia(), 2.0);
+    }
+
+    #[test]
+    fn test_glider_center_of_mass_drifts() {
+        let mut universe = Universe::<12, 12>::new();
+        universe.seed_glider(2, 2).unwrap();
+        let (start_row, start_col) = universe.center_of_mass().unwrap();
+        for step in 1..=4 {
+            universe.evolve();
+            let (row, col) = universe.center_of_mass().unwrap();
+            // The phases differ in shape, so the drift is only a quarter cell on average
+            let expected = step as f32 * 0.25;
+            assert!((row - start_row - expected).abs() < 0.4);
+            assert!((col - start_col - expected).abs() < 0.4);
+        }
+        assert_eq!(
+            universe.center_of_mass(),
+            Some((start_row + 1.0, start_col + 1.0))
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use modular_bitfield::prelude::*;
+
+use crate::{check_bounds, BoundaryCondition};
+
+/// The state of a cell in Brian's Brain
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BitfieldSpecifier)]
+#[bits = 2]
+pub enum BrainState {
+    Off = 0,
+    On = 1,
+    Dying = 2,
+}
+
+/// Cell of a Brian's Brain universe
+#[bitfield(bits = 8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BrainCell {
+    state: BrainState,
+    live_neighbors: B6,
+}
+
+impl BrainCell {
+    /// Sets a new state for the cell based on the current state and On neighbors
+    fn evolve(&mut self) {
+        let state = match (self.state(), self.live_neighbors()) {
+            (BrainState::Off, 2) => BrainState::On,
+            (BrainState::Off, _) => BrainState::Off,
+            (BrainState::On, _) => BrainState::Dying,
+            (BrainState::Dying, _) => BrainState::Off,
+        };
+        self.set_state(state);
+    }
+
+    /// Returns true if the cell is On
+    pub fn is_on(&self) -> bool {
+        self.state() == BrainState::On
+    }
+}
+
+impl Default for BrainCell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A Brian's Brain universe with a fixed width and height
+///
+/// Off cells turn On with exactly two On neighbors, On cells always start
+/// Dying and Dying cells always turn Off. The grid wraps around its edges.
+pub struct BrainUniverse<const W: usize, const H: usize> {
+    grid: [[BrainCell; W]; H],
+    grid_back: [[BrainCell; W]; H],
+}
+
+impl<const W: usize, const H: usize> BrainUniverse<W, H> {
+    /// Creates a universe where every cell is Off
+    pub const fn new() -> Self {
+        BrainUniverse {
+            grid: [[BrainCell::new(); W]; H],
+            grid_back: [[BrainCell::new(); W]; H],
+        }
+    }
+
+    /// Returns the reference to the grid
+    pub fn grid(&self) -> &[[BrainCell; W]; H] {
+        &self.grid
+    }
+
+    /// Sets the state of the cell
+    ///
+    /// Panics if the cell is outside the grid.
+    pub fn set_cell(&mut self, row: usize, column: usize, state: BrainState) {
+        check_bounds::<W, H>(row, column);
+        self.grid[row][column].set_state(state);
+    }
+
+    /// Returns the state of the cell
+    ///
+    /// Panics if the cell is outside the grid.
+    pub fn state(&self, row: usize, column: usize) -> BrainState {
+        check_bounds::<W, H>(row, column);
+        self.grid[row][column].state()
+    }
+
+    /// Sets every cell randomly On or Off
+    #[cfg(feature = "rand_core")]
+    pub fn seed_random<R: rand_core::RngCore>(&mut self, rng: &mut R) {
+        for cell in self.grid.iter_mut().flatten() {
+            let state = if rng.next_u32() & 1 == 1 {
+                BrainState::On
+            } else {
+                BrainState::Off
+            };
+            cell.set_state(state);
+        }
+    }
+
+    /// Evolves the universe
+    pub fn evolve(&mut self) {
+        for row in 0..H {
+            for column in 0..W {
+                let mut cell = self.grid[row][column];
+                cell.set_live_neighbors(self.live_neighbor_count_brain(row, column));
+                cell.evolve();
+                self.grid_back[row][column] = cell;
+            }
+        }
+
+        core::mem::swap(&mut self.grid, &mut self.grid_back);
+    }
+
+    /// Counts the On neighbors of a cell, wrapping around the edges
+    fn live_neighbor_count_brain(&self, row: usize, column: usize) -> u8 {
+        let mut count = 0;
+        BoundaryCondition::Toroidal.for_each_moore_neighbor(row, column, H, W, |row, column| {
+            count += self.grid[row][column].is_on() as u8;
+        });
+        count
+    }
+}
+
+impl<const W: usize, const H: usize> Default for BrainUniverse<W, H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_on_cell_starts_dying() {
+        let mut universe = BrainUniverse::<5, 5>::new();
+        universe.set_cell(2, 2, BrainState::On);
+        universe.evolve();
+        assert_eq!(universe.state(2, 2), BrainState::Dying);
+    }
+
+    #[test]
+    fn test_dying_cell_turns_off() {
+        let mut universe = BrainUniverse::<5, 5>::new();
+        universe.set_cell(2, 2, BrainState::Dying);
+        universe.evolve();
+        assert_eq!(universe.state(2, 2), BrainState::Off);
+    }
+
+    #[test]
+    fn test_off_cell_with_two_on_neighbors_turns_on() {
+        let mut universe = BrainUniverse::<5, 5>::new();
+        universe.set_cell(1, 1, BrainState::On);
+        universe.set_cell(1, 3, BrainState::On);
+        universe.evolve();
+        assert_eq!(universe.state(2, 2), BrainState::On);
+        assert_eq!(universe.state(1, 2), BrainState::On);
+        assert_eq!(universe.state(1, 1), BrainState::Dying);
+    }
+
+    #[test]
+    fn test_dying_neighbors_are_not_counted() {
+        let mut universe = BrainUniverse::<5, 5>::new();
+        universe.set_cell(1, 1, BrainState::Dying);
+        universe.set_cell(1, 3, BrainState::Dying);
+        universe.evolve();
+        assert_eq!(universe.state(2, 2), BrainState::Off);
+    }
+
+    #[test]
+    fn test_glider_moves_one_cell_per_generation() {
+        let mut universe = BrainUniverse::<8, 6>::new();
+        universe.set_cell(2, 1, BrainState::Dying);
+        universe.set_cell(3, 1, BrainState::Dying);
+        universe.set_cell(2, 2, BrainState::On);
+        universe.set_cell(3, 2, BrainState::On);
+
+        universe.evolve();
+
+        for row in 0..6 {
+            for column in 0..8 {
+                let expected = match (row, column) {
+                    (2, 2) | (3, 2) => BrainState::Dying,
+                    (2, 3) | (3, 3) => BrainState::On,
+                    _ => BrainState::Off,
+                };
+                assert_eq!(universe.state(row, column), expected);
+            }
+        }
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_seed_random() {
+        use rand::SeedableRng;
+
+        let mut universe = BrainUniverse::<10, 10>::new();
+        universe.seed_random(&mut rand::rngs::StdRng::seed_from_u64(7));
+        let on = universe
+            .grid()
+            .iter()
+            .flatten()
+            .filter(|c| c.is_on())
+            .count();
+        assert!(on > 0 && on < 100);
+        assert!(universe
+            .grid()
+            .iter()
+            .flatten()
+            .all(|c| c.state() != BrainState::Dying));
+    }
+
+    #[test]
+    #[should_panic(expected = "cell (3, 1) is out of bounds for a 4x3 universe")]
+    fn test_set_cell_out_of_bounds() {
+        BrainUniverse::<4, 3>::new().set_cell(3, 1, BrainState::On);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{BoundaryCondition, Neighborhood, RuleSet, State, Universe};
+#[cfg(feature = "alloc")]
+use crate::{PatternGrid, PlacementError};
+
+/// Collects the settings and alive cells of a universe before creating it
+///
+/// ```
+/// use game_of_life_core::UniverseBuilder;
+///
+/// let glider = UniverseBuilder::<5, 5>::new()
+///     .alive(0, 1)
+///     .alive(1, 2)
+///     .alive(2, 0)
+///     .alive(2, 1)
+///     .alive(2, 2)
+///     .build();
+/// assert_eq!(glider.count_alive(), 5);
+/// ```
+#[derive(Clone)]
+pub struct UniverseBuilder<
+    const W: usize,
+    const H: usize,
+    N: Neighborhood = crate::MooreNeighborhood,
+> {
+    universe: Universe<W, H, N>,
+    out_of_bounds: Option<(usize, usize)>,
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> UniverseBuilder<W, H, N> {
+    /// Starts from an empty universe with the default rule and boundary
+    pub const fn new() -> Self {
+        UniverseBuilder {
+            universe: Universe::new(),
+            out_of_bounds: None,
+        }
+    }
+
+    /// Makes the cell alive
+    ///
+    /// A position outside the grid is reported when the universe is built.
+    pub fn alive(mut self, row: usize, column: usize) -> Self {
+        if row < H && column < W {
+            self.universe.set_cell(row, column, State::Alive);
+        } else if self.out_of_bounds.is_none() {
+            self.out_of_bounds = Some((row, column));
+        }
+        self
+    }
+
+    /// Sets the rule the universe evolves by
+    pub fn rule(mut self, rule: RuleSet) -> Self {
+        self.universe.rule = rule;
+        self
+    }
+
+    /// Sets the boundary condition
+    pub fn boundary(mut self, boundary: BoundaryCondition) -> Self {
+        self.universe.boundary = boundary;
+        self
+    }
+
+    /// Makes the alive cells of the pattern alive, with its top-left corner at
+    /// `(top, left)`
+    #[cfg(feature = "alloc")]
+    pub fn from_pattern(
+        mut self,
+        pattern: &PatternGrid,
+        top: usize,
+        left: usize,
+    ) -> Result<Self, PlacementError> {
+        self.universe.place_pattern(pattern, top, left)?;
+        Ok(self)
+    }
+
+    /// Creates the universe
+    ///
+    /// Panics if any cell passed to [`alive`](Self::alive) is outside the grid.
+    pub fn build(self) -> Universe<W, H, N> {
+        if let Some((row, column)) = self.out_of_bounds {
+            Universe::<W, H, N>::check_bounds(row, column);
+        }
+        self.universe
+    }
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Default for UniverseBuilder<W, H, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builds_glider() {
+        let universe = UniverseBuilder::<5, 5>::new()
+            .alive(0, 1)
+            .alive(1, 2)
+            .alive(2, 0)
+            .alive(2, 1)
+            .alive(2, 2)
+            .build();
+        let mut expected = Universe::<5, 5>::new();
+        expected.seed_glider(0, 0).unwrap();
+        assert_eq!(universe, expected);
+    }
+
+    #[test]
+    fn test_duplicate_alive_is_idempotent() {
+        let once = UniverseBuilder::<4, 4>::new().alive(1, 1).build();
+        let twice = UniverseBuilder::<4, 4>::new()
+            .alive(1, 1)
+            .alive(1, 1)
+            .build();
+        assert_eq!(once, twice);
+        assert_eq!(twice.count_alive(), 1);
+    }
+
+    #[test]
+    fn test_rule_and_boundary() {
+        let universe = UniverseBuilder::<4, 4>::new()
+            .rule(RuleSet::HIGH_LIFE)
+            .boundary(BoundaryCondition::FixedDead)
+            .build();
+        assert_eq!(universe.rule(), RuleSet::HIGH_LIFE);
+        assert_eq!(universe.boundary(), BoundaryCondition::FixedDead);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_from_pattern() {
+        use alloc::vec;
+
+        let mut pattern = PatternGrid::new(2, 1);
+        pattern.cells[0] = vec![true, true];
+        let universe = UniverseBuilder::<4, 4>::new()
+            .from_pattern(&pattern, 3, 2)
+            .unwrap()
+            .build();
+        assert!(universe.is_alive(3, 2) && universe.is_alive(3, 3));
+        assert_eq!(
+            UniverseBuilder::<4, 4>::new()
+                .from_pattern(&pattern, 3, 3)
+                .err(),
+            Some(PlacementError::OutOfBounds)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "cell (4, 0) is out of bounds for a 4x4 universe")]
+    fn test_out_of_bounds_panics_at_build() {
+        let builder = UniverseBuilder::<4, 4>::new().alive(4, 0).alive(0, 0);
+        builder.build();
+    }
+}
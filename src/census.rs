@@ -0,0 +1,124 @@
+use crate::{Neighborhood, Universe};
+
+/// Population statistics gathered by [`Universe::run_census`]
+///
+/// Population figures cover every generation observed, from the one the
+/// census started at to the last one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CensusReport {
+    /// The number of generations evolved
+    pub steps: usize,
+    pub initial_population: usize,
+    pub final_population: usize,
+    pub max_population: usize,
+    pub min_population: usize,
+    /// The number of cells born over all steps
+    pub total_births: u64,
+    /// The number of cells that died over all steps
+    pub total_deaths: u64,
+    #[cfg(feature = "std")]
+    pub mean_population: f32,
+    /// The population standard deviation of the population counts
+    #[cfg(feature = "std")]
+    pub population_std_dev: f32,
+    /// True if every cell was dead after one of the steps
+    pub reached_empty: bool,
+    /// True if every cell was alive after one of the steps
+    pub reached_full: bool,
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Evolves the universe `steps` times and returns statistics about its
+    /// population along the way
+    pub fn run_census(&mut self, steps: usize) -> CensusReport {
+        let initial_population = self.count_alive();
+        let mut report = CensusReport {
+            steps,
+            initial_population,
+            final_population: initial_population,
+            max_population: initial_population,
+            min_population: initial_population,
+            total_births: 0,
+            total_deaths: 0,
+            #[cfg(feature = "std")]
+            mean_population: 0.0,
+            #[cfg(feature = "std")]
+            population_std_dev: 0.0,
+            reached_empty: false,
+            reached_full: false,
+        };
+        #[cfg(feature = "std")]
+        let (mut sum, mut sum_of_squares) = (0.0, 0.0);
+        #[cfg(feature = "std")]
+        let mut record = |population: usize| {
+            sum += population as f64;
+            sum_of_squares += (population * population) as f64;
+        };
+        #[cfg(feature = "std")]
+        record(initial_population);
+
+        for _ in 0..steps {
+            self.evolve();
+            let (births, deaths) = self.count_changes();
+            report.total_births += births as u64;
+            report.total_deaths += deaths as u64;
+
+            let population = self.count_alive();
+            report.max_population = report.max_population.max(population);
+            report.min_population = report.min_population.min(population);
+            report.reached_empty |= population == 0;
+            report.reached_full |= population == W * H;
+            report.final_population = population;
+            #[cfg(feature = "std")]
+            record(population);
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let count = (steps + 1) as f64;
+            let mean = sum / count;
+            report.mean_population = mean as f32;
+            report.population_std_dev =
+                (sum_of_squares / count - mean * mean).max(0.0).sqrt() as f32;
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blinker_census() {
+        let mut universe = Universe::<8, 8>::new();
+        universe.seed_blinker(3, 2).unwrap();
+        let report = universe.run_census(100);
+        assert_eq!(report.steps, 100);
+        assert_eq!(report.total_births, 200);
+        assert_eq!(report.total_births, report.total_deaths);
+        assert_eq!(report.max_population, 3);
+        assert_eq!(report.min_population, 3);
+        assert_eq!(report.final_population, 3);
+        assert!(!report.reached_empty && !report.reached_full);
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(report.mean_population, 3.0);
+            assert!(report.population_std_dev.abs() < 1e-6);
+        }
+        assert_eq!(universe.generation(), 100);
+    }
+
+    #[test]
+    fn test_census_of_dying_universe() {
+        let mut universe = Universe::<16, 16>::from_seed_deterministic(80, 230);
+        let initial = universe.count_alive();
+        let report = universe.run_census(20);
+        assert_eq!(report.initial_population, initial);
+        assert_eq!(report.max_population, initial);
+        assert!(report.reached_empty);
+        assert_eq!(report.min_population, 0);
+        assert_eq!(report.final_population, 0);
+        assert_eq!(report.total_deaths - report.total_births, initial as u64);
+    }
+}
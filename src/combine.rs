@@ -0,0 +1,360 @@
+use crate::{Neighborhood, State, Universe};
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Returns a universe whose cells are alive where either universe is alive
+    ///
+    /// This and the other set operations keep the rule and boundary of `self`
+    /// and restart the generation at 0.
+    pub fn merge(&self, other: &Universe<W, H, N>) -> Universe<W, H, N> {
+        self.combined(other, |a, b| a || b)
+    }
+
+    /// Returns a universe whose cells are alive where both universes are alive
+    pub fn intersect(&self, other: &Universe<W, H, N>) -> Universe<W, H, N> {
+        self.combined(other, |a, b| a && b)
+    }
+
+    /// Returns a universe whose cells are alive where exactly one universe is
+    /// alive
+    pub fn xor_with(&self, other: &Universe<W, H, N>) -> Universe<W, H, N> {
+        self.combined(other, |a, b| a != b)
+    }
+
+    /// Returns a universe whose cells are alive where this one is dead
+    pub fn invert(&self) -> Universe<W, H, N> {
+        self.map_cells(|_, _, cell| match cell.state() {
+            State::Dead => State::Alive,
+            State::Alive => State::Dead,
+        })
+    }
+
+    /// Flips every cell between alive and dead, keeping the generation
+    pub fn invert_in_place(&mut self) {
+        self.grid = self.invert().grid;
+        self.evolved = false;
+    }
+
+    /// Kills every alive cell where `mask` is false
+    ///
+    /// Cells where `mask` is true are left as they are, and no cell is ever
+    /// brought to life. Applying a mask after every `evolve()` keeps the
+    /// pattern out of the masked-off region.
+    pub fn apply_mask(&mut self, mask: &[[bool; W]; H]) {
+        self.kill_where(mask, false);
+    }
+
+    /// Kills every alive cell where `mask` is true, the inverse of
+    /// [`apply_mask`](Self::apply_mask)
+    pub fn apply_mask_invert(&mut self, mask: &[[bool; W]; H]) {
+        self.kill_where(mask, true);
+    }
+
+    fn kill_where(&mut self, mask: &[[bool; W]; H], kill: bool) {
+        self.evolved = false;
+        for (cells, mask) in self.grid.iter_mut().zip(mask) {
+            for (cell, &masked) in cells.iter_mut().zip(mask) {
+                if masked == kill {
+                    cell.set_state(State::Dead);
+                }
+            }
+        }
+    }
+
+    /// Returns the number of cells whose state differs between the universes
+    pub fn hamming_distance(&self, other: &Universe<W, H, N>) -> usize {
+        let (ours, theirs) = (self.state_grid(), other.state_grid());
+        ours.iter()
+            .flatten()
+            .zip(theirs.iter().flatten())
+            .filter(|(a, b)| a != b)
+            .count()
+    }
+
+    /// Returns the fraction of cells whose state differs between the
+    /// universes, from 0.0 to 1.0
+    pub fn normalized_hamming_distance(&self, other: &Universe<W, H, N>) -> f32 {
+        self.hamming_distance(other) as f32 / (W * H) as f32
+    }
+
+    /// Returns the Pearson correlation of the two grids, with alive cells
+    /// counted as 1 and dead cells as 0
+    ///
+    /// The result runs from -1.0 for a universe and its inverse to 1.0 for
+    /// identical universes. Returns `None` if either universe is empty or
+    /// full, as the correlation of a constant grid is undefined.
+    #[cfg(feature = "std")]
+    pub fn correlation(&self, other: &Universe<W, H, N>) -> Option<f32> {
+        let (mut ours, mut theirs, mut both) = (0usize, 0usize, 0usize);
+        for (a, b) in self.grid.iter().flatten().zip(other.grid.iter().flatten()) {
+            let (a, b) = (a.is_alive(), b.is_alive());
+            ours += a as usize;
+            theirs += b as usize;
+            both += (a && b) as usize;
+        }
+        let cells = (W * H) as f64;
+        let (ours, theirs, both) = (ours as f64, theirs as f64, both as f64);
+        let variance = (cells * ours - ours * ours) * (cells * theirs - theirs * theirs);
+        if variance == 0.0 {
+            return None;
+        }
+        Some(((cells * both - ours * theirs) / variance.sqrt()) as f32)
+    }
+
+    /// Returns, for each cell, the number of `states` in which it is alive
+    pub fn heat_map(states: &[Universe<W, H, N>]) -> [[u32; W]; H] {
+        let mut heat = [[0; W]; H];
+        for universe in states {
+            universe.for_each_alive(|row, column| heat[row][column] += 1);
+        }
+        heat
+    }
+
+    /// Returns, for each cell, the fraction of `states` in which it is alive,
+    /// or 0.0 for every cell when `states` is empty
+    pub fn normalized_heat_map(states: &[Universe<W, H, N>]) -> [[f32; W]; H] {
+        let heat = Self::heat_map(states);
+        let mut normalized = [[0.0; W]; H];
+        if !states.is_empty() {
+            for (normalized, heat) in normalized.iter_mut().flatten().zip(heat.iter().flatten()) {
+                *normalized = *heat as f32 / states.len() as f32;
+            }
+        }
+        normalized
+    }
+
+    fn combined(
+        &self,
+        other: &Universe<W, H, N>,
+        op: impl Fn(bool, bool) -> bool,
+    ) -> Universe<W, H, N> {
+        let mut universe = self.empty_like();
+        for row in 0..H {
+            for column in 0..W {
+                if op(
+                    self.grid[row][column].is_alive(),
+                    other.grid[row][column].is_alive(),
+                ) {
+                    universe.grid[row][column].set_state(State::Alive);
+                }
+            }
+        }
+        universe
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RuleSet;
+
+    fn glider() -> Universe<10, 10> {
+        let mut universe = Universe::new();
+        universe.seed_glider(1, 1).unwrap();
+        universe
+    }
+
+    #[test]
+    fn test_merge_with_self() {
+        let universe = glider();
+        assert_eq!(universe.merge(&universe), universe);
+    }
+
+    #[test]
+    fn test_intersect_with_inverse_is_empty() {
+        let universe = glider();
+        let mut full = Universe::<10, 10>::new();
+        full.fill(State::Alive);
+        let inverse = universe.xor_with(&full);
+        assert_eq!(inverse.count_alive(), 95);
+        assert!(universe.intersect(&inverse).is_empty());
+        assert_eq!(universe.intersect(&full), universe);
+    }
+
+    #[test]
+    fn test_xor() {
+        let universe = glider();
+        assert!(universe.xor_with(&universe).is_empty());
+        assert_eq!(universe.xor_with(&Universe::new()), universe);
+    }
+
+    #[test]
+    fn test_merge_disjoint_gliders() {
+        let mut other = Universe::<10, 10>::new();
+        other.seed_glider(6, 6).unwrap();
+        assert_eq!(glider().merge(&other).count_alive(), 10);
+    }
+
+    #[test]
+    fn test_hamming_distance() {
+        let universe = glider();
+        assert_eq!(universe.hamming_distance(&universe), 0);
+
+        let mut full = Universe::<10, 10>::new();
+        full.fill(State::Alive);
+        let inverse = universe.xor_with(&full);
+        assert_eq!(universe.hamming_distance(&inverse), 100);
+        assert_eq!(universe.normalized_hamming_distance(&inverse), 1.0);
+
+        let mut moved = universe.clone();
+        moved.evolve();
+        let distance = universe.hamming_distance(&moved);
+        assert_eq!(distance, moved.hamming_distance(&universe));
+        assert_ne!(distance, 0);
+        assert_ne!(universe, moved);
+    }
+
+    #[test]
+    fn test_random_universes_differ_in_half_the_cells() {
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(67);
+        let mut random = || {
+            let mut universe = Universe::<32, 32>::new();
+            for row in 0..32 {
+                for column in 0..32 {
+                    if rng.gen_bool(0.5) {
+                        universe.set_cell(row, column, State::Alive);
+                    }
+                }
+            }
+            universe
+        };
+        let (a, b) = (random(), random());
+        let distance = a.normalized_hamming_distance(&b);
+        assert!((0.45..0.55).contains(&distance), "distance {}", distance);
+        assert_eq!(distance, b.normalized_hamming_distance(&a));
+        assert_eq!(a.hamming_distance(&b) == 0, a == b);
+    }
+
+    #[test]
+    fn test_invert() {
+        let universe = glider();
+        let inverse = universe.invert();
+        assert_eq!(inverse.count_alive(), 95);
+        assert_eq!(inverse.invert(), universe);
+        assert!(universe.merge(&inverse).is_full());
+        assert!(universe.intersect(&inverse).is_empty());
+
+        let mut flipped = universe.clone();
+        flipped.invert_in_place();
+        assert_eq!(flipped, inverse);
+        flipped.invert_in_place();
+        assert_eq!(flipped, universe);
+    }
+
+    #[test]
+    fn test_day_and_night_is_self_complementary() {
+        let mut universe = Universe::<10, 10>::with_rule(RuleSet::DAY_AND_NIGHT);
+        universe.seed_glider(1, 1).unwrap();
+        universe.seed_block(6, 5).unwrap();
+        for _ in 0..4 {
+            let mut inverse = universe.invert();
+            inverse.evolve();
+            universe.evolve();
+            assert_eq!(universe, inverse.invert());
+        }
+    }
+
+    #[test]
+    fn test_apply_mask() {
+        let mut universe = Universe::<10, 10>::from_seed_deterministic(87, 128);
+        let original = universe.clone();
+        universe.apply_mask(&[[true; 10]; 10]);
+        assert_eq!(universe, original);
+        universe.apply_mask_invert(&[[false; 10]; 10]);
+        assert_eq!(universe, original);
+        universe.apply_mask(&[[false; 10]; 10]);
+        assert!(universe.is_empty());
+
+        let mut universe = original.clone();
+        universe.apply_mask_invert(&[[true; 10]; 10]);
+        assert!(universe.is_empty());
+    }
+
+    #[test]
+    fn test_apply_quadrant_mask() {
+        let mut mask = [[false; 10]; 10];
+        for row in mask.iter_mut().take(5) {
+            row[..5].fill(true);
+        }
+        let mut universe = Universe::<10, 10>::from_seed_deterministic(87, 128);
+        let original = universe.clone();
+        universe.apply_mask(&mask);
+        universe.for_each_alive(|row, column| assert!(row < 5 && column < 5));
+        let in_quadrant = original
+            .alive_cells()
+            .filter(|&(row, column)| row < 5 && column < 5)
+            .count();
+        assert_eq!(universe.count_alive(), in_quadrant);
+
+        let mut inverse = original.clone();
+        inverse.apply_mask_invert(&mask);
+        assert_eq!(universe.merge(&inverse), original);
+
+        for _ in 0..10 {
+            universe.evolve();
+            universe.apply_mask(&mask);
+            universe.for_each_alive(|row, column| assert!(row < 5 && column < 5));
+        }
+    }
+
+    #[test]
+    fn test_heat_map_of_blinker() {
+        let mut universe = Universe::<5, 5>::new();
+        universe.seed_blinker(2, 1).unwrap();
+        let mut states = [universe.clone(), universe.clone()];
+        states[1].evolve();
+        let states: [Universe<5, 5>; 10] = core::array::from_fn(|i| states[i % 2].clone());
+
+        let heat = Universe::heat_map(&states);
+        let normalized = Universe::normalized_heat_map(&states);
+        for row in 0..5 {
+            for column in 0..5 {
+                let expected = match (row, column) {
+                    (2, 2) => 10,
+                    (2, 1) | (2, 3) | (1, 2) | (3, 2) => 5,
+                    _ => 0,
+                };
+                assert_eq!(heat[row][column], expected);
+                assert_eq!(normalized[row][column], expected as f32 / 10.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_heat_map_of_single_state() {
+        let universe = glider();
+        let heat = Universe::heat_map(core::slice::from_ref(&universe));
+        for (row, counts) in heat.iter().enumerate() {
+            for (column, &count) in counts.iter().enumerate() {
+                assert_eq!(count, universe.is_alive(row, column) as u32);
+            }
+        }
+        assert_eq!(Universe::<10, 10>::heat_map(&[]), [[0; 10]; 10]);
+        assert_eq!(
+            Universe::<10, 10>::normalized_heat_map(&[]),
+            [[0.0; 10]; 10]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_correlation() {
+        let universe = glider();
+        assert!((universe.correlation(&universe).unwrap() - 1.0).abs() < 1e-6);
+        assert!((universe.correlation(&universe.invert()).unwrap() + 1.0).abs() < 1e-6);
+        assert_eq!(universe.correlation(&Universe::new()), None);
+        assert_eq!(Universe::<10, 10>::new().correlation(&universe), None);
+        assert_eq!(universe.correlation(&Universe::new().invert()), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_independent_universes_are_uncorrelated() {
+        for seed in 0..8 {
+            let a = Universe::<64, 64>::from_seed_deterministic(seed, 128);
+            let b = Universe::<64, 64>::from_seed_deterministic(seed + 100, 128);
+            assert!(a.correlation(&b).unwrap().abs() < 0.05);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use alloc::vec::Vec;
+
+use crate::{BoundaryCondition, MooreNeighborhood, Neighborhood, Universe};
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Returns the groups of alive cells that touch, including diagonally
+    ///
+    /// Cells on opposite edges are not connected, whatever the boundary
+    /// condition. Components are ordered by their first cell in row-major
+    /// order, and the cells of each component are in row-major order.
+    pub fn connected_components(&self) -> Vec<Vec<(usize, usize)>> {
+        let mut components = Vec::new();
+        self.visit_components(|cells| {
+            let mut cells = cells.to_vec();
+            cells.sort_unstable();
+            components.push(cells);
+        });
+        components
+    }
+
+    /// Returns the number of connected components of alive cells, as found by
+    /// [`connected_components`](Self::connected_components)
+    pub fn connected_component_count(&self) -> usize {
+        let mut count = 0;
+        self.visit_components(|_| count += 1);
+        count
+    }
+
+    /// Calls `f` with the cells of each component, in the order they were
+    /// reached by a breadth-first search
+    fn visit_components(&self, mut f: impl FnMut(&[(usize, usize)])) {
+        let mut visited = [[false; W]; H];
+        let mut component = Vec::new();
+        for row in 0..H {
+            for column in 0..W {
+                if visited[row][column] || !self.grid[row][column].is_alive() {
+                    continue;
+                }
+                component.clear();
+                visited[row][column] = true;
+                component.push((row, column));
+                let mut next = 0;
+                while let Some(&(row, column)) = component.get(next) {
+                    next += 1;
+                    MooreNeighborhood::for_each_offset(|delta_row, delta_col| {
+                        let edge = BoundaryCondition::FixedDead;
+                        if let (Some(neighbor_row), Some(neighbor_col)) = (
+                            edge.row_offset(row, delta_row, H),
+                            edge.col_offset(column, delta_col, W),
+                        ) {
+                            if !visited[neighbor_row][neighbor_col]
+                                && self.grid[neighbor_row][neighbor_col].is_alive()
+                            {
+                                visited[neighbor_row][neighbor_col] = true;
+                                component.push((neighbor_row, neighbor_col));
+                            }
+                        }
+                    });
+                }
+                f(&component);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::State;
+
+    #[test]
+    fn test_block_is_one_component() {
+        let mut universe = Universe::<6, 6>::new();
+        universe.seed_block(1, 1).unwrap();
+        assert_eq!(
+            universe.connected_components(),
+            vec![vec![(1, 1), (1, 2), (2, 1), (2, 2)]]
+        );
+        assert_eq!(universe.connected_component_count(), 1);
+    }
+
+    #[test]
+    fn test_separate_blocks() {
+        let mut universe = Universe::<8, 8>::new();
+        universe.seed_block(4, 0).unwrap();
+        universe.seed_block(0, 5).unwrap();
+        let components = universe.connected_components();
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0][0], (0, 5));
+        assert_eq!(components[1][0], (4, 0));
+        assert_eq!(universe.connected_component_count(), 2);
+    }
+
+    #[test]
+    fn test_diagonal_cells_connect() {
+        let mut universe = Universe::<5, 5>::new();
+        universe
+            .set_cells_alive(&[(0, 0), (1, 1), (2, 2), (0, 4)])
+            .unwrap();
+        assert_eq!(universe.connected_component_count(), 2);
+        universe.set_cell(4, 4, State::Alive);
+        assert_eq!(universe.connected_component_count(), 3);
+    }
+
+    #[test]
+    fn test_empty_and_single_cell() {
+        let mut universe = Universe::<4, 4>::new();
+        assert!(universe.connected_components().is_empty());
+        universe.set_cell(3, 2, State::Alive);
+        assert_eq!(universe.connected_components(), vec![vec![(3, 2)]]);
+    }
+}
@@ -0,0 +1,105 @@
+use core::fmt;
+
+use crate::{Neighborhood, Universe};
+
+/// Errors returned by [`Universe::validate_internal_consistency`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ConsistencyError {
+    /// A cell's cached live neighbor count differs from a fresh count of the
+    /// generation it was computed from
+    LiveNeighborMismatch {
+        row: usize,
+        col: usize,
+        stored: u8,
+        computed: u8,
+    },
+}
+
+impl fmt::Display for ConsistencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConsistencyError::LiveNeighborMismatch {
+                row,
+                col,
+                stored,
+                computed,
+            } => write!(
+                f,
+                "cell ({}, {}) caches {} live neighbors but has {}",
+                row, col, stored, computed
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConsistencyError {}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Checks that every cell's cached live neighbor count matches the
+    /// previous generation, which `evolve()` counted it from
+    ///
+    /// A failure points to a bug in the evolution code. Operations that
+    /// replace the whole grid, such as `translate_in_place`, reset the cached
+    /// counts and so fail this check until the next `evolve()`. Returns the
+    /// first mismatch in row-major order.
+    pub fn validate_internal_consistency(&self) -> Result<(), ConsistencyError> {
+        for (row, cells) in self.grid.iter().enumerate() {
+            for (col, cell) in cells.iter().enumerate() {
+                let stored = cell.live_neighbors();
+                let computed = Self::neighbor_count_in(&self.grid_back, self.boundary, row, col);
+                if stored != computed {
+                    return Err(ConsistencyError::LiveNeighborMismatch {
+                        row,
+                        col,
+                        stored,
+                        computed,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BoundaryCondition;
+
+    #[test]
+    fn test_evolved_universe_is_consistent() {
+        let mut universe = Universe::<10, 10>::from_seed_deterministic(92, 100);
+        assert_eq!(universe.validate_internal_consistency(), Ok(()));
+        for _ in 0..5 {
+            universe.evolve();
+            assert_eq!(universe.validate_internal_consistency(), Ok(()));
+        }
+
+        let mut bounded = Universe::<6, 6>::with_boundary(BoundaryCondition::Reflective);
+        bounded.seed_glider(0, 0).unwrap();
+        bounded.step_n(3);
+        assert_eq!(bounded.validate_internal_consistency(), Ok(()));
+    }
+
+    #[test]
+    fn test_corrupted_count_is_reported() {
+        let mut universe = Universe::<8, 8>::new();
+        universe.seed_block(2, 2).unwrap();
+        universe.evolve();
+        universe.grid[3][2].set_live_neighbors(5);
+        let before = universe.clone();
+        assert_eq!(
+            universe.validate_internal_consistency(),
+            Err(ConsistencyError::LiveNeighborMismatch {
+                row: 3,
+                col: 2,
+                stored: 5,
+                computed: 3,
+            })
+        );
+        assert_eq!(universe, before);
+        assert_eq!(universe.grid[3][2].live_neighbors(), 5);
+    }
+}
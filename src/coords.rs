@@ -0,0 +1,196 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::DynamicUniverse;
+use crate::{CellOutOfBounds, Neighborhood, State, Universe};
+
+/// Errors returned when a universe cannot be built from a coordinate list
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CoordError {
+    /// A coordinate lies outside the requested dimensions
+    OutOfBounds {
+        row: usize,
+        column: usize,
+        width: usize,
+        height: usize,
+    },
+    /// Either the width or the height is zero
+    ZeroDimension,
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for CoordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoordError::OutOfBounds {
+                row,
+                column,
+                width,
+                height,
+            } => write!(
+                f,
+                "cell ({}, {}) is out of bounds for a {}x{} universe",
+                row, column, width, height
+            ),
+            CoordError::ZeroDimension => write!(f, "universe dimensions must be non-zero"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CoordError {}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Returns the `(row, column)` of every alive cell in row-major order
+    #[cfg(feature = "alloc")]
+    pub fn to_coordinate_list(&self) -> Vec<(usize, usize)> {
+        self.alive_cells().collect()
+    }
+
+    /// Creates a universe whose alive cells are exactly the given
+    /// `(row, column)` coordinates
+    ///
+    /// Repeated coordinates are allowed. Returns the first coordinate outside
+    /// the grid as an error.
+    pub fn from_alive_coords<I>(coords: I) -> Result<Self, CellOutOfBounds>
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        let mut universe = Self::new();
+        for (row, column) in coords {
+            if row >= H || column >= W {
+                return Err(CellOutOfBounds { row, column });
+            }
+            universe.grid[row][column].set_state(State::Alive);
+        }
+        Ok(universe)
+    }
+
+    /// Returns the `(row, column)` of every alive cell in row-major order,
+    /// scanning the grid lazily
+    pub fn to_alive_coords_sorted(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.alive_cells()
+    }
+
+    /// Writes the `(row, column)` of alive cells in row-major order into
+    /// `buf` and returns how many were written
+    ///
+    /// Writing stops once `buf` is full, so a return value of `buf.len()` may
+    /// mean some cells were left out.
+    pub fn alive_coords_into(&self, buf: &mut [(usize, usize)]) -> usize {
+        let mut written = 0;
+        for (slot, coords) in buf.iter_mut().zip(self.alive_cells()) {
+            *slot = coords;
+            written += 1;
+        }
+        written
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl DynamicUniverse {
+    /// Creates a universe of the given size whose alive cells are exactly
+    /// the listed `(row, column)` coordinates
+    pub fn from_coordinate_list(
+        width: usize,
+        height: usize,
+        coords: &[(usize, usize)],
+    ) -> Result<DynamicUniverse, CoordError> {
+        // Checked first, since every coordinate is out of bounds of an empty grid
+        let mut universe =
+            DynamicUniverse::new(width, height).map_err(|_| CoordError::ZeroDimension)?;
+        if let Some(&(row, column)) = coords
+            .iter()
+            .find(|&&(row, column)| row >= height || column >= width)
+        {
+            return Err(CoordError::OutOfBounds {
+                row,
+                column,
+                width,
+                height,
+            });
+        }
+        for &(row, column) in coords {
+            universe.set_cell(row, column, State::Alive);
+        }
+        Ok(universe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_coordinate_list_round_trip() {
+        let mut universe = Universe::<7, 5>::new();
+        universe.seed_glider(1, 2).unwrap();
+        let coords = universe.to_coordinate_list();
+        assert_eq!(coords, [(1, 3), (2, 4), (3, 2), (3, 3), (3, 4)]);
+
+        let rebuilt = DynamicUniverse::from_coordinate_list(7, 5, &coords).unwrap();
+        assert_eq!(rebuilt, DynamicUniverse::from_universe_lossy(&universe));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_from_coordinate_list_errors() {
+        assert_eq!(
+            DynamicUniverse::from_coordinate_list(4, 3, &[(0, 0), (3, 1)]),
+            Err(CoordError::OutOfBounds {
+                row: 3,
+                column: 1,
+                width: 4,
+                height: 3,
+            })
+        );
+        assert_eq!(
+            DynamicUniverse::from_coordinate_list(0, 3, &[(0, 0)]),
+            Err(CoordError::ZeroDimension)
+        );
+    }
+
+    #[test]
+    fn test_alive_coords_into_buffer() {
+        let mut universe = Universe::<6, 6>::new();
+        universe.seed_block(2, 2).unwrap();
+        let mut buf = [(0, 0); 8];
+        assert_eq!(universe.alive_coords_into(&mut buf), 4);
+        assert_eq!(buf[..4], [(2, 2), (2, 3), (3, 2), (3, 3)]);
+
+        let mut small = [(0, 0); 3];
+        assert_eq!(universe.alive_coords_into(&mut small), 3);
+        assert_eq!(Universe::<6, 6>::new().alive_coords_into(&mut small), 0);
+    }
+
+    #[test]
+    fn test_from_alive_coords() {
+        let mut universe = Universe::<8, 6>::new();
+        universe.seed_glider(2, 3).unwrap();
+        let mut coords = [(0, 0); 5];
+        for (slot, coords) in coords.iter_mut().zip(universe.to_alive_coords_sorted()) {
+            *slot = coords;
+        }
+        assert_eq!(
+            Universe::<8, 6>::from_alive_coords(coords),
+            Ok(universe.clone())
+        );
+
+        // Listing a cell twice sets it once
+        let doubled = coords.iter().chain(coords.iter()).copied();
+        assert_eq!(Universe::<8, 6>::from_alive_coords(doubled), Ok(universe));
+
+        let empty = Universe::<8, 6>::from_alive_coords(core::iter::empty()).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(
+            Universe::<8, 6>::from_alive_coords([(1, 1), (6, 0)]),
+            Err(CellOutOfBounds { row: 6, column: 0 })
+        );
+    }
+}
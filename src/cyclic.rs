@@ -0,0 +1,199 @@
+use crate::{check_bounds, BoundaryCondition};
+
+/// Cell of a cyclic universe with `N` states
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CyclicCell<const N: u8> {
+    state: u8,
+}
+
+impl<const N: u8> CyclicCell<N> {
+    const CHECK_STATES: () = assert!(
+        N >= 2 && N <= 16,
+        "a cyclic automaton needs between 2 and 16 states"
+    );
+
+    /// Creates a cell in state 0
+    pub const fn new() -> Self {
+        let () = Self::CHECK_STATES;
+        CyclicCell { state: 0 }
+    }
+
+    /// Returns the state of the cell, from 0 to `N - 1`
+    pub fn state(&self) -> u8 {
+        self.state
+    }
+
+    /// Returns the state that follows this cell's state
+    pub fn successor(&self) -> u8 {
+        (self.state + 1) % N
+    }
+}
+
+/// A cyclic cellular automaton with a fixed width and height and `N` states
+/// per cell
+///
+/// A cell in state `s` advances to `(s + 1) % N` when at least `threshold` of
+/// its Moore neighbors are already in that state. The grid wraps around its
+/// edges.
+pub struct CyclicUniverse<const W: usize, const H: usize, const N: u8> {
+    grid: [[CyclicCell<N>; W]; H],
+    grid_back: [[CyclicCell<N>; W]; H],
+    threshold: u8,
+}
+
+impl<const W: usize, const H: usize, const N: u8> CyclicUniverse<W, H, N> {
+    /// Creates a universe with every cell in state 0
+    ///
+    /// Panics if `threshold` is not between 1 and 8.
+    pub const fn new(threshold: u8) -> Self {
+        assert!(
+            threshold >= 1 && threshold <= 8,
+            "threshold is out of range 1-8"
+        );
+        CyclicUniverse {
+            grid: [[CyclicCell::new(); W]; H],
+            grid_back: [[CyclicCell::new(); W]; H],
+            threshold,
+        }
+    }
+
+    /// Returns the reference to the grid
+    pub fn grid(&self) -> &[[CyclicCell<N>; W]; H] {
+        &self.grid
+    }
+
+    /// Returns the number of successor neighbors a cell needs to advance
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Sets the state of the cell
+    ///
+    /// Panics if the cell is out of bounds or the state is not less than `N`.
+    pub fn set_cell(&mut self, row: usize, column: usize, state: u8) {
+        check_bounds::<W, H>(row, column);
+        assert!(
+            state < N,
+            "state {} is out of range for a {}-state universe",
+            state,
+            N
+        );
+        self.grid[row][column].state = state;
+    }
+
+    /// Returns the state of the cell
+    ///
+    /// Panics if the cell is out of bounds.
+    pub fn state(&self, row: usize, column: usize) -> u8 {
+        check_bounds::<W, H>(row, column);
+        self.grid[row][column].state()
+    }
+
+    /// Evolves the universe
+    pub fn evolve(&mut self) {
+        for row in 0..H {
+            for column in 0..W {
+                let mut cell = self.grid[row][column];
+                let successor = cell.successor();
+                if self.neighbor_count_in_state(row, column, successor) >= self.threshold {
+                    cell.state = successor;
+                }
+                self.grid_back[row][column] = cell;
+            }
+        }
+
+        core::mem::swap(&mut self.grid, &mut self.grid_back);
+    }
+
+    /// Counts the neighbors of a cell in the given state, wrapping around the
+    /// edges
+    fn neighbor_count_in_state(&self, row: usize, column: usize, state: u8) -> u8 {
+        let mut count = 0;
+        BoundaryCondition::Toroidal.for_each_moore_neighbor(row, column, H, W, |row, column| {
+            count += (self.grid[row][column].state() == state) as u8;
+        });
+        count
+    }
+}
+
+impl<const W: usize, const H: usize> Default for CyclicUniverse<W, H, 3> {
+    /// The Cyclic-3 rule, with 3 states and a threshold of 3
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_two_states_spread_to_every_neighbor() {
+        let mut universe = CyclicUniverse::<5, 5, 2>::new(1);
+        universe.set_cell(2, 2, 1);
+        universe.evolve();
+        for row in 0..5 {
+            for column in 0..5 {
+                let expected = match (row, column) {
+                    (2, 2) => 0,
+                    (1..=3, 1..=3) => 1,
+                    _ => 0,
+                };
+                assert_eq!(universe.state(row, column), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_single_successor_neighbor_advances_cell() {
+        let mut universe = CyclicUniverse::<5, 5, 3>::new(1);
+        universe.set_cell(2, 3, 1);
+        universe.evolve();
+        assert_eq!(universe.state(2, 2), 1);
+        assert_eq!(universe.state(0, 0), 0);
+    }
+
+    #[test]
+    fn test_threshold_holds_cell_back() {
+        let mut universe = CyclicUniverse::<5, 5, 3>::default();
+        assert_eq!(universe.threshold(), 3);
+        universe.set_cell(1, 1, 1);
+        universe.set_cell(1, 2, 1);
+        universe.evolve();
+        assert_eq!(universe.state(2, 2), 0);
+
+        universe.set_cell(1, 3, 1);
+        universe.evolve();
+        assert_eq!(universe.state(2, 2), 1);
+    }
+
+    #[test]
+    fn test_full_cycle_takes_n_steps() {
+        // A uniform universe with a threshold of 1 needs one neighbor in the
+        // successor state, so seed a stripe of each state
+        let mut universe = CyclicUniverse::<3, 3, 3>::new(1);
+        for row in 0..3 {
+            for column in 0..3 {
+                universe.set_cell(row, column, column as u8);
+            }
+        }
+        let initial = universe.grid;
+        for step in 1..=3 {
+            universe.evolve();
+            assert_eq!(universe.grid == initial, step == 3);
+            assert_eq!(universe.state(0, 0), step % 3);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "threshold is out of range 1-8")]
+    fn test_threshold_out_of_range() {
+        CyclicUniverse::<5, 5, 3>::new(9);
+    }
+
+    #[test]
+    #[should_panic(expected = "cell (3, 1) is out of bounds for a 4x3 universe")]
+    fn test_set_cell_out_of_bounds() {
+        CyclicUniverse::<4, 3, 3>::new(1).set_cell(3, 1, 0);
+    }
+}
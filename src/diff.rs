@@ -0,0 +1,128 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{ChangedCellIter, Neighborhood, State, StateChange, Universe};
+
+/// A cell that changed state between two universes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CellChange {
+    pub row: usize,
+    pub col: usize,
+    pub change: StateChange,
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Returns the cells whose state differs between `before` and `after`, in
+    /// row-major order
+    #[cfg(feature = "alloc")]
+    pub fn diff(before: &Self, after: &Self) -> Vec<CellChange> {
+        Self::changes(before, after).collect()
+    }
+
+    /// Writes the cells whose state differs between `before` and `after` into
+    /// `buf` and returns how many were written
+    ///
+    /// Writing stops once `buf` is full, so a return value of `buf.len()` may
+    /// mean some changes were left out.
+    pub fn diff_into(before: &Self, after: &Self, buf: &mut [CellChange]) -> usize {
+        let mut written = 0;
+        for (slot, change) in buf.iter_mut().zip(Self::changes(before, after)) {
+            *slot = change;
+            written += 1;
+        }
+        written
+    }
+
+    /// Applies changes produced by [`diff`](Self::diff), making born cells
+    /// alive and dead cells dead
+    pub fn apply_diff(&mut self, changes: &[CellChange]) {
+        for change in changes {
+            let state = match change.change {
+                StateChange::Born => State::Alive,
+                StateChange::Died => State::Dead,
+            };
+            self.set_cell(change.row, change.col, state);
+        }
+    }
+
+    fn changes<'a>(before: &'a Self, after: &'a Self) -> impl Iterator<Item = CellChange> + 'a {
+        ChangedCellIter::new(&after.grid, &before.grid).map(|(row, col, change)| CellChange {
+            row,
+            col,
+            change,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blinker_phases() -> (Universe<5, 5>, Universe<5, 5>) {
+        let mut before = Universe::new();
+        before.seed_blinker(2, 1).unwrap();
+        let mut after = before.clone();
+        after.evolve();
+        (before, after)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_blinker_diff() {
+        let (before, after) = blinker_phases();
+        let changes = Universe::diff(&before, &after);
+        assert_eq!(changes.len(), 4);
+        assert_eq!(
+            changes[0],
+            CellChange {
+                row: 1,
+                col: 2,
+                change: StateChange::Born,
+            }
+        );
+        assert_eq!(
+            changes
+                .iter()
+                .filter(|c| c.change == StateChange::Died)
+                .count(),
+            2
+        );
+        assert!(Universe::diff(&before, &before).is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_apply_diff() {
+        let (before, after) = blinker_phases();
+        let mut applied = before.clone();
+        applied.apply_diff(&Universe::diff(&before, &after));
+        assert_eq!(applied, after);
+    }
+
+    #[test]
+    fn test_diff_into_buffer() {
+        let (before, after) = blinker_phases();
+        let empty = CellChange {
+            row: 0,
+            col: 0,
+            change: StateChange::Born,
+        };
+        let mut buf = [empty; 8];
+        assert_eq!(Universe::diff_into(&before, &after, &mut buf), 4);
+        let expected = [
+            (1, 2, StateChange::Born),
+            (2, 1, StateChange::Died),
+            (2, 3, StateChange::Died),
+            (3, 2, StateChange::Born),
+        ]
+        .map(|(row, col, change)| CellChange { row, col, change });
+        assert_eq!(buf[..4], expected);
+        let mut applied = before.clone();
+        applied.apply_diff(&buf[..4]);
+        assert_eq!(applied, after);
+
+        let mut small = [empty; 3];
+        assert_eq!(Universe::diff_into(&before, &after, &mut small), 3);
+        assert_eq!(Universe::diff_into(&after, &after, &mut small), 0);
+    }
+}
@@ -0,0 +1,139 @@
+use core::fmt;
+use core::fmt::Write;
+
+#[cfg(feature = "defmt")]
+use crate::Cell;
+use crate::{Neighborhood, Universe};
+
+const ALIVE: char = '█';
+const DEAD: char = '·';
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Returns a 64-bit FNV-1a hash of the cell states in row-major order
+    ///
+    /// Universes with identical grids have the same checksum, regardless of
+    /// their rule, boundary or generation.
+    pub fn checksum(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0100_0000_01b3;
+        self.grid.iter().flatten().fold(OFFSET_BASIS, |hash, cell| {
+            (hash ^ cell.is_alive() as u64).wrapping_mul(PRIME)
+        })
+    }
+}
+
+/// Draws a `Generation: N` line followed by one line per row, with `█` for
+/// alive and `·` for dead cells
+impl<const W: usize, const H: usize, N: Neighborhood> fmt::Display for Universe<W, H, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Generation: {}", self.generation)?;
+        for row in self.grid.iter() {
+            for cell in row {
+                f.write_char(if cell.is_alive() { ALIVE } else { DEAD })?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+/// Summarizes the grid as a checksum rather than printing every cell
+impl<const W: usize, const H: usize, N: Neighborhood> fmt::Debug for Universe<W, H, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Universe")
+            .field("width", &W)
+            .field("height", &H)
+            .field("generation", &self.generation)
+            .field("alive", &self.count_alive())
+            .field("checksum", &format_args!("{:#018x}", self.checksum()))
+            .finish()
+    }
+}
+
+/// Formats a cell as `Cell(state=Alive, neighbors=3)`
+#[cfg(feature = "defmt")]
+impl defmt::Format for Cell {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Cell(state={}, neighbors={=u8})",
+            self.state(),
+            self.live_neighbors()
+        )
+    }
+}
+
+/// Summarizes the universe as `Universe(WxH gen=N alive=K)`
+#[cfg(feature = "defmt")]
+impl<const W: usize, const H: usize, N: Neighborhood> defmt::Format for Universe<W, H, N> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Universe({=usize}x{=usize} gen={=u64} alive={=usize})",
+            W,
+            H,
+            self.generation,
+            self.count_alive()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{State, Universe};
+    #[cfg(feature = "alloc")]
+    use alloc::format;
+
+    fn blinker() -> Universe<3, 3> {
+        let mut universe = Universe::new();
+        universe.set_cell(1, 0, State::Alive);
+        universe.set_cell(1, 1, State::Alive);
+        universe.set_cell(1, 2, State::Alive);
+        universe
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_display() {
+        let mut universe = blinker();
+        assert_eq!(format!("{}", universe), "Generation: 0\n···\n███\n···\n");
+        universe.evolve();
+        assert_eq!(format!("{}", universe), "Generation: 1\n███\n███\n███\n");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_debug() {
+        let universe = blinker();
+        let debug = format!("{:?}", universe);
+        assert!(debug.starts_with("Universe { width: 3, height: 3, generation: 0, alive: 3"));
+        assert_eq!(debug, format!("{:?}", blinker()));
+        assert_ne!(debug, format!("{:?}", Universe::<3, 3>::new()));
+    }
+
+    #[test]
+    fn test_checksum() {
+        let mut universe = blinker();
+        assert_eq!(universe.checksum(), blinker().checksum());
+        universe.toggle_cell(0, 0);
+        assert_ne!(universe.checksum(), blinker().checksum());
+        universe.toggle_cell(0, 0);
+        universe.reset_generation();
+        assert_eq!(universe.checksum(), blinker().checksum());
+    }
+
+    // Logging needs a global logger, which only embedded targets provide, so
+    // the host can only check that the implementations exist
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn test_defmt_format_implemented() {
+        fn assert_format<T: defmt::Format>() {}
+        assert_format::<State>();
+        assert_format::<crate::Cell>();
+        assert_format::<Universe<3, 3>>();
+        assert_format::<crate::RuleParseError>();
+        assert_format::<crate::PlacementError>();
+        assert_format::<crate::CellOutOfBounds>();
+        assert_format::<crate::Life106ParseError>();
+    }
+}
@@ -0,0 +1,494 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Cell, Neighborhood, RuleSet, State, Universe, UniverseTrait};
+
+/// Errors returned when a universe cannot be created with the requested dimensions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DimError {
+    /// Either the width or the height is zero
+    ZeroDimension,
+    /// The number of cells does not fit in a `usize`
+    TooLarge,
+    /// The dimensions differ from the ones required by the target type
+    Mismatch {
+        expected_width: usize,
+        expected_height: usize,
+        width: usize,
+        height: usize,
+    },
+}
+
+impl fmt::Display for DimError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DimError::ZeroDimension => write!(f, "universe dimensions must be non-zero"),
+            DimError::TooLarge => write!(f, "universe has too many cells"),
+            DimError::Mismatch {
+                expected_width,
+                expected_height,
+                width,
+                height,
+            } => write!(
+                f,
+                "expected a {}x{} universe, found {}x{}",
+                expected_width, expected_height, width, height
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DimError {}
+
+/// Errors returned when a universe cannot be tiled to the requested size
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TileError {
+    /// The source is wider or taller than the requested size
+    SourceTooLarge,
+    /// The source has a zero width or height, so there is nothing to repeat
+    EmptySource,
+}
+
+impl fmt::Display for TileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TileError::SourceTooLarge => write!(f, "source is larger than the tiled universe"),
+            TileError::EmptySource => write!(f, "source universe has no cells to tile"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TileError {}
+
+/// A heap-allocated universe whose dimensions are chosen at runtime
+///
+/// Cells are stored row-major in a flat buffer with stride `width`, one byte
+/// per cell holding its `State`.
+#[derive(Clone, Debug)]
+pub struct DynamicUniverse {
+    cells: Vec<u8>,
+    cells_back: Vec<u8>,
+    width: usize,
+    height: usize,
+    generation: u64,
+}
+
+impl DynamicUniverse {
+    /// Creates an all-dead universe of the given size
+    pub fn new(width: usize, height: usize) -> Result<Self, DimError> {
+        if width == 0 || height == 0 {
+            return Err(DimError::ZeroDimension);
+        }
+        if width.checked_mul(height).is_none() {
+            return Err(DimError::TooLarge);
+        }
+        Ok(DynamicUniverse::blank(width, height))
+    }
+
+    /// Returns the number of times the universe has evolved
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns the number of columns
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cells in row-major order, one byte per cell holding its `State`
+    pub fn cells(&self) -> &[u8] {
+        &self.cells
+    }
+
+    /// Sets the state of the cell
+    pub fn set_cell(&mut self, row: usize, column: usize, state: State) {
+        let index = self.index(row, column);
+        self.cells[index] = state as u8;
+    }
+
+    /// Returns true if the cell at the given position is alive
+    pub fn is_alive(&self, row: usize, column: usize) -> bool {
+        self.cells[self.index(row, column)] == State::Alive as u8
+    }
+
+    /// Returns the number of alive cells
+    pub fn count_alive(&self) -> usize {
+        self.cells
+            .iter()
+            .filter(|&&state| state == State::Alive as u8)
+            .count()
+    }
+
+    /// Evolves the universe
+    pub fn evolve(&mut self) {
+        for row in 0..self.height {
+            for column in 0..self.width {
+                let mut cell = Cell::new().with_state(self.state(row, column));
+                cell.set_live_neighbors(self.live_neighbor_count(row, column));
+                cell.evolve(&RuleSet::CONWAY);
+                let index = self.index(row, column);
+                self.cells_back[index] = cell.state() as u8;
+            }
+        }
+
+        core::mem::swap(&mut self.cells, &mut self.cells_back);
+        self.generation += 1;
+    }
+
+    /// Places `right` beside `left` in a universe `W + W2` cells wide, at
+    /// generation 0
+    pub fn hstack<const W: usize, const W2: usize, const H: usize, N, N2>(
+        left: &Universe<W, H, N>,
+        right: &Universe<W2, H, N2>,
+    ) -> DynamicUniverse
+    where
+        N: Neighborhood,
+        N2: Neighborhood,
+    {
+        let mut stacked = DynamicUniverse::blank(W + W2, H);
+        left.for_each_alive(|row, column| stacked.set_cell(row, column, State::Alive));
+        right.for_each_alive(|row, column| stacked.set_cell(row, W + column, State::Alive));
+        stacked
+    }
+
+    /// Places `bottom` below `top` in a universe `H + H2` cells high, at
+    /// generation 0
+    pub fn vstack<const W: usize, const H: usize, const H2: usize, N, N2>(
+        top: &Universe<W, H, N>,
+        bottom: &Universe<W, H2, N2>,
+    ) -> DynamicUniverse
+    where
+        N: Neighborhood,
+        N2: Neighborhood,
+    {
+        let mut stacked = DynamicUniverse::blank(W, H + H2);
+        top.for_each_alive(|row, column| stacked.set_cell(row, column, State::Alive));
+        bottom.for_each_alive(|row, column| stacked.set_cell(H + row, column, State::Alive));
+        stacked
+    }
+
+    /// Repeats `source` across a `new_width` by `new_height` universe,
+    /// cutting off the copies that reach past the right and bottom edges
+    pub fn tile_to<const W: usize, const H: usize, N: Neighborhood>(
+        source: &Universe<W, H, N>,
+        new_width: usize,
+        new_height: usize,
+    ) -> Result<DynamicUniverse, TileError> {
+        if W == 0 || H == 0 {
+            return Err(TileError::EmptySource);
+        }
+        if W > new_width || H > new_height {
+            return Err(TileError::SourceTooLarge);
+        }
+        let mut tiled = DynamicUniverse::blank(new_width, new_height);
+        for row in 0..new_height {
+            for column in 0..new_width {
+                if source.is_alive(row % H, column % W) {
+                    tiled.set_cell(row, column, State::Alive);
+                }
+            }
+        }
+        Ok(tiled)
+    }
+
+    /// Creates an all-dead universe without checking for zero dimensions
+    ///
+    /// Panics if the number of cells overflows a `usize`.
+    fn blank(width: usize, height: usize) -> Self {
+        let len = width
+            .checked_mul(height)
+            .expect("universe has too many cells");
+        DynamicUniverse {
+            cells: vec![State::Dead as u8; len],
+            cells_back: vec![State::Dead as u8; len],
+            width,
+            height,
+            generation: 0,
+        }
+    }
+
+    /// Copies the cells and generation of `universe`
+    ///
+    /// This is lossy: a `DynamicUniverse` always evolves by Conway's rule on
+    /// a toroidal Moore grid, so the rule, boundary condition and
+    /// neighborhood of `universe` are dropped.
+    pub fn from_universe_lossy<const W: usize, const H: usize, N: Neighborhood>(
+        universe: &Universe<W, H, N>,
+    ) -> Self {
+        let cells: Vec<u8> = universe
+            .grid
+            .iter()
+            .flatten()
+            .map(|cell| cell.state() as u8)
+            .collect();
+        DynamicUniverse {
+            cells_back: vec![State::Dead as u8; cells.len()],
+            cells,
+            width: W,
+            height: H,
+            generation: universe.generation,
+        }
+    }
+
+    fn index(&self, row: usize, column: usize) -> usize {
+        assert!(
+            row < self.height && column < self.width,
+            "cell ({}, {}) is out of bounds for a {}x{} universe",
+            row,
+            column,
+            self.width,
+            self.height
+        );
+        row * self.width + column
+    }
+
+    fn state(&self, row: usize, column: usize) -> State {
+        if self.is_alive(row, column) {
+            State::Alive
+        } else {
+            State::Dead
+        }
+    }
+
+    fn live_neighbor_count(&self, row: usize, column: usize) -> u8 {
+        let mut count = 0;
+        for delta_row in [self.height - 1, 0, 1] {
+            for delta_col in [self.width - 1, 0, 1] {
+                if delta_row == 0 && delta_col == 0 {
+                    continue;
+                }
+                // Calculate the neighbor's coordinates with wrapping
+                let neighbor_row = (row + delta_row) % self.height;
+                let neighbor_col = (column + delta_col) % self.width;
+                count += self.cells[neighbor_row * self.width + neighbor_col];
+            }
+        }
+        count
+    }
+}
+
+/// Universes are equal when they have the same dimensions and cell states,
+/// whatever their generation
+impl PartialEq for DynamicUniverse {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height && self.cells == other.cells
+    }
+}
+
+impl Eq for DynamicUniverse {}
+
+impl UniverseTrait for DynamicUniverse {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn set_cell(&mut self, row: usize, column: usize, state: State) {
+        DynamicUniverse::set_cell(self, row, column, state)
+    }
+
+    fn is_alive(&self, row: usize, column: usize) -> bool {
+        DynamicUniverse::is_alive(self, row, column)
+    }
+
+    fn evolve(&mut self) {
+        DynamicUniverse::evolve(self)
+    }
+
+    fn count_alive(&self) -> usize {
+        DynamicUniverse::count_alive(self)
+    }
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> TryFrom<DynamicUniverse>
+    for Universe<W, H, N>
+{
+    type Error = DimError;
+
+    fn try_from(dynamic: DynamicUniverse) -> Result<Self, Self::Error> {
+        if dynamic.width != W || dynamic.height != H {
+            return Err(DimError::Mismatch {
+                expected_width: W,
+                expected_height: H,
+                width: dynamic.width,
+                height: dynamic.height,
+            });
+        }
+        let mut universe = Universe::new();
+        for row in 0..H {
+            for column in 0..W {
+                universe.set_cell(row, column, dynamic.state(row, column));
+            }
+        }
+        universe.generation = dynamic.generation;
+        Ok(universe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glider<U: UniverseTrait>(universe: &mut U) {
+        universe.set_cell(0, 1, State::Alive);
+        universe.set_cell(1, 2, State::Alive);
+        universe.set_cell(2, 0, State::Alive);
+        universe.set_cell(2, 1, State::Alive);
+        universe.set_cell(2, 2, State::Alive);
+    }
+
+    #[test]
+    fn test_new_rejects_zero_dimension() {
+        assert_eq!(DynamicUniverse::new(0, 3), Err(DimError::ZeroDimension));
+        assert_eq!(DynamicUniverse::new(3, 0), Err(DimError::ZeroDimension));
+        assert_eq!(DynamicUniverse::new(usize::MAX, 2), Err(DimError::TooLarge));
+    }
+
+    #[test]
+    fn test_new_dynamic_universe() {
+        let universe = DynamicUniverse::new(4, 3).unwrap();
+        assert_eq!(universe.width(), 4);
+        assert_eq!(universe.height(), 3);
+        assert_eq!(universe.count_alive(), 0);
+    }
+
+    #[test]
+    fn test_evolves_like_universe() {
+        let mut fixed = Universe::<6, 5>::new();
+        let mut dynamic = DynamicUniverse::new(6, 5).unwrap();
+        glider(&mut fixed);
+        glider(&mut dynamic);
+
+        for _ in 0..12 {
+            fixed.evolve();
+            dynamic.evolve();
+            for row in 0..5 {
+                for column in 0..6 {
+                    assert_eq!(fixed.is_alive(row, column), dynamic.is_alive(row, column));
+                }
+            }
+        }
+        assert_eq!(dynamic.count_alive(), 5);
+        assert_eq!(dynamic.generation(), 12);
+        assert_eq!(
+            DynamicUniverse::from_universe_lossy(&fixed).generation(),
+            12
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        let mut fixed = Universe::<4, 4>::new();
+        glider(&mut fixed);
+        let expected = fixed.state_grid();
+
+        let dynamic = DynamicUniverse::from_universe_lossy(&fixed);
+        assert_eq!(dynamic.count_alive(), 5);
+        assert_eq!(dynamic.cells()[2 * 4 + 1], State::Alive as u8);
+        assert!(dynamic.is_alive(2, 1));
+
+        let fixed: Universe<4, 4> = dynamic.clone().try_into().unwrap();
+        assert_eq!(fixed.state_grid(), expected);
+
+        let mismatch: Result<Universe<3, 4>, _> = dynamic.try_into();
+        assert_eq!(
+            mismatch.err(),
+            Some(DimError::Mismatch {
+                expected_width: 3,
+                expected_height: 4,
+                width: 4,
+                height: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn test_hstack() {
+        let mut square = Universe::<3, 3>::new();
+        glider(&mut square);
+        square.evolve();
+        let stacked = DynamicUniverse::hstack(&square, &square);
+        assert_eq!((stacked.width(), stacked.height()), (6, 3));
+        assert_eq!(stacked.generation(), 0);
+        for row in 0..3 {
+            for column in 0..3 {
+                assert_eq!(stacked.is_alive(row, column), square.is_alive(row, column));
+                assert_eq!(
+                    stacked.is_alive(row, column + 3),
+                    square.is_alive(row, column)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_vstack() {
+        let mut top = Universe::<5, 4>::new();
+        glider(&mut top);
+        let stacked = DynamicUniverse::vstack(&top, &Universe::<5, 6>::new());
+        assert_eq!((stacked.width(), stacked.height()), (5, 10));
+        let mut expected = DynamicUniverse::new(5, 10).unwrap();
+        glider(&mut expected);
+        assert_eq!(stacked, expected);
+    }
+
+    #[test]
+    fn test_tile_blocks() {
+        let block = Universe::<3, 3>::from([
+            [true, true, false],
+            [true, true, false],
+            [false, false, false],
+        ]);
+        let tiled = DynamicUniverse::tile_to(&block, 8, 7).unwrap();
+        assert_eq!((tiled.width(), tiled.height()), (8, 7));
+        for row in 0..7 {
+            for column in 0..8 {
+                assert_eq!(tiled.is_alive(row, column), row % 3 < 2 && column % 3 < 2);
+            }
+        }
+
+        let mut cell = Universe::<1, 1>::new();
+        cell.set_cell(0, 0, State::Alive);
+        assert_eq!(
+            DynamicUniverse::tile_to(&cell, 5, 9).unwrap().count_alive(),
+            45
+        );
+    }
+
+    #[test]
+    fn test_tile_to_own_size() {
+        let mut universe = Universe::<6, 5>::new();
+        glider(&mut universe);
+        let tiled = DynamicUniverse::tile_to(&universe, 6, 5).unwrap();
+        assert_eq!(tiled, DynamicUniverse::from_universe_lossy(&universe));
+        assert_eq!(
+            DynamicUniverse::tile_to(&Universe::<6, 5>::new(), 5, 5),
+            Err(TileError::SourceTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_tile_rejects_empty_source() {
+        assert_eq!(
+            DynamicUniverse::tile_to(&Universe::<0, 3>::new(), 4, 4),
+            Err(TileError::EmptySource)
+        );
+        assert_eq!(
+            DynamicUniverse::tile_to(&Universe::<3, 0>::new(), 4, 4),
+            Err(TileError::EmptySource)
+        );
+    }
+}
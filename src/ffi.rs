@@ -0,0 +1,167 @@
+//! C API over an opaque universe handle, enabled by the `c-ffi` feature
+//!
+//! The declarations are mirrored in `include/game_of_life_core.h`. Every
+//! function taking a handle accepts either null, which is ignored, or a
+//! pointer returned by `universe_new()` that has not been freed.
+
+use std::boxed::Box;
+
+use crate::{DynamicUniverse, State};
+
+/// A universe owned by C code, created by `universe_new()`
+pub struct OpaqueUniverse {
+    universe: DynamicUniverse,
+}
+
+/// Creates an all-dead universe, or returns null if either dimension is zero
+#[no_mangle]
+pub extern "C" fn universe_new(width: u32, height: u32) -> *mut OpaqueUniverse {
+    match DynamicUniverse::new(width as usize, height as usize) {
+        Ok(universe) => Box::into_raw(Box::new(OpaqueUniverse { universe })),
+        Err(_) => core::ptr::null_mut(),
+    }
+}
+
+/// Frees a universe created by `universe_new()`
+///
+/// # Safety
+///
+/// `u` must be null or a live handle, and must not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn universe_free(u: *mut OpaqueUniverse) {
+    if !u.is_null() {
+        drop(Box::from_raw(u));
+    }
+}
+
+/// Evolves the universe
+///
+/// # Safety
+///
+/// `u` must be null or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn universe_evolve(u: *mut OpaqueUniverse) {
+    if let Some(u) = u.as_mut() {
+        u.universe.evolve();
+    }
+}
+
+/// Sets the cell alive or dead. Out-of-range coordinates are ignored.
+///
+/// # Safety
+///
+/// `u` must be null or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn universe_set_cell(
+    u: *mut OpaqueUniverse,
+    row: u32,
+    col: u32,
+    alive: bool,
+) {
+    if let Some(u) = u.as_mut() {
+        let (row, col) = (row as usize, col as usize);
+        if row < u.universe.height() && col < u.universe.width() {
+            let state = if alive { State::Alive } else { State::Dead };
+            u.universe.set_cell(row, col, state);
+        }
+    }
+}
+
+/// Returns true if the cell is alive, false if it is dead or out of range
+///
+/// # Safety
+///
+/// `u` must be null or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn universe_is_alive(u: *const OpaqueUniverse, row: u32, col: u32) -> bool {
+    match u.as_ref() {
+        Some(u) => {
+            let (row, col) = (row as usize, col as usize);
+            row < u.universe.height() && col < u.universe.width() && u.universe.is_alive(row, col)
+        }
+        None => false,
+    }
+}
+
+/// Returns the number of times the universe has evolved, or 0 for null
+///
+/// # Safety
+///
+/// `u` must be null or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn universe_generation(u: *const OpaqueUniverse) -> u64 {
+    u.as_ref().map_or(0, |u| u.universe.generation())
+}
+
+/// Copies the cells into `buf` in row-major order, `1` for alive and `0` for
+/// dead
+///
+/// Returns 0 on success, or -1 if the universe or buffer is null or `len` is
+/// less than `width * height`.
+///
+/// # Safety
+///
+/// `u` must be null or a live handle, and `buf` must be null or valid for
+/// writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn universe_copy_to_buffer(
+    u: *const OpaqueUniverse,
+    buf: *mut u8,
+    len: usize,
+) -> i32 {
+    let Some(u) = u.as_ref() else {
+        return -1;
+    };
+    let cells = u.universe.cells();
+    if buf.is_null() || len < cells.len() {
+        return -1;
+    }
+    core::ptr::copy_nonoverlapping(cells.as_ptr(), buf, cells.len());
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blinker_through_ffi() {
+        let u = universe_new(5, 5);
+        assert!(!u.is_null());
+        unsafe {
+            for col in 1..4 {
+                universe_set_cell(u, 2, col, true);
+            }
+            universe_evolve(u);
+            assert_eq!(universe_generation(u), 1);
+            assert!(universe_is_alive(u, 1, 2));
+            assert!(!universe_is_alive(u, 2, 1));
+            assert!(!universe_is_alive(u, 5, 0));
+
+            let mut buf = [0xffu8; 25];
+            assert_eq!(universe_copy_to_buffer(u, buf.as_mut_ptr(), 24), -1);
+            assert_eq!(universe_copy_to_buffer(u, buf.as_mut_ptr(), buf.len()), 0);
+            assert_eq!(buf.iter().filter(|&&cell| cell == 1).count(), 3);
+            assert_eq!(buf[3 * 5 + 2], 1);
+
+            universe_free(u);
+        }
+    }
+
+    #[test]
+    fn test_null_handles() {
+        assert!(universe_new(0, 4).is_null());
+        unsafe {
+            universe_evolve(core::ptr::null_mut());
+            universe_set_cell(core::ptr::null_mut(), 0, 0, true);
+            assert!(!universe_is_alive(core::ptr::null(), 0, 0));
+            assert_eq!(universe_generation(core::ptr::null()), 0);
+            let mut buf = [0u8; 4];
+            assert_eq!(
+                universe_copy_to_buffer(core::ptr::null(), buf.as_mut_ptr(), 4),
+                -1
+            );
+            universe_free(core::ptr::null_mut());
+        }
+    }
+}
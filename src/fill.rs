@@ -0,0 +1,96 @@
+use alloc::collections::VecDeque;
+
+use crate::{BoundaryCondition, Neighborhood, State, Universe, VonNeumannNeighborhood};
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Sets every cell that shares the start cell's state and is connected to
+    /// it through orthogonal neighbors of that state to `fill_state`, and
+    /// returns how many cells changed
+    ///
+    /// Cells on opposite edges are not connected, whatever the boundary
+    /// condition. Does nothing if the start cell is already `fill_state`.
+    ///
+    /// Panics if the start position is outside the grid.
+    pub fn flood_fill(&mut self, start_row: usize, start_col: usize, fill_state: State) -> usize {
+        Self::check_bounds(start_row, start_col);
+        let target = self.grid[start_row][start_col].state();
+        if target == fill_state {
+            return 0;
+        }
+
+        let mut queue = VecDeque::new();
+        self.set_cell(start_row, start_col, fill_state);
+        queue.push_back((start_row, start_col));
+        let mut filled = 0;
+        while let Some((row, column)) = queue.pop_front() {
+            filled += 1;
+            VonNeumannNeighborhood::for_each_offset(|delta_row, delta_col| {
+                let edge = BoundaryCondition::FixedDead;
+                if let (Some(neighbor_row), Some(neighbor_col)) = (
+                    edge.row_offset(row, delta_row, H),
+                    edge.col_offset(column, delta_col, W),
+                ) {
+                    // Filled cells no longer match the target, so each cell is queued once
+                    if self.grid[neighbor_row][neighbor_col].state() == target {
+                        self.set_cell(neighbor_row, neighbor_col, fill_state);
+                        queue.push_back((neighbor_row, neighbor_col));
+                    }
+                }
+            });
+        }
+        filled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ring() -> Universe<7, 7> {
+        let mut universe = Universe::new();
+        for index in 1..6 {
+            universe.set_cell(1, index, State::Alive);
+            universe.set_cell(5, index, State::Alive);
+            universe.set_cell(index, 1, State::Alive);
+            universe.set_cell(index, 5, State::Alive);
+        }
+        universe
+    }
+
+    #[test]
+    fn test_fill_empty_universe() {
+        let mut universe = Universe::<8, 6>::new();
+        assert_eq!(universe.flood_fill(5, 7, State::Alive), 48);
+        assert!(universe.is_full());
+    }
+
+    #[test]
+    fn test_fill_inside_ring() {
+        let mut universe = ring();
+        assert_eq!(universe.flood_fill(3, 3, State::Alive), 9);
+        assert_eq!(universe.count_alive(), 25);
+        assert!(!universe.is_alive(0, 0));
+
+        // Filling outside the ring leaves the interior alone
+        let mut universe = ring();
+        assert_eq!(universe.flood_fill(0, 0, State::Alive), 24);
+        assert!(!universe.is_alive(3, 3));
+    }
+
+    #[test]
+    fn test_fill_same_state_is_noop() {
+        let mut universe = ring();
+        assert_eq!(universe.flood_fill(1, 1, State::Alive), 0);
+        assert_eq!(universe, ring());
+
+        // Clearing the ring through one of its cells removes all of it
+        assert_eq!(universe.flood_fill(1, 1, State::Dead), 16);
+        assert!(universe.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "cell (7, 0) is out of bounds for a 7x7 universe")]
+    fn test_fill_out_of_bounds() {
+        ring().flood_fill(7, 0, State::Alive);
+    }
+}
@@ -0,0 +1,151 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{DynamicUniverse, State};
+
+/// How [`DynamicUniverse::from_ascii`] treats rows of different lengths
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AsciiParseMode {
+    /// Every row must be as long as the first one
+    #[default]
+    Strict,
+    /// Rows shorter than the longest one are padded with dead cells
+    Lenient,
+}
+
+/// Errors returned when parsing ASCII art into a universe
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AsciiParseError {
+    /// There are no non-blank lines
+    EmptyInput,
+    /// In strict mode, a row differs in length from the first row
+    InconsistentRowLength {
+        row: usize,
+        expected: usize,
+        got: usize,
+    },
+    /// A character that is neither a dead nor an alive cell was found
+    UnrecognizedCharacter { row: usize, col: usize, ch: char },
+}
+
+impl fmt::Display for AsciiParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AsciiParseError::EmptyInput => write!(f, "input has no rows"),
+            AsciiParseError::InconsistentRowLength { row, expected, got } => write!(
+                f,
+                "row {} has {} cells but {} were expected",
+                row, got, expected
+            ),
+            AsciiParseError::UnrecognizedCharacter { row, col, ch } => {
+                write!(f, "unrecognized character '{}' at ({}, {})", ch, row, col)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AsciiParseError {}
+
+impl DynamicUniverse {
+    /// Parses ASCII art, one line per row, into a universe
+    ///
+    /// `.` and space are dead cells, while `*`, `#` and `O` are alive ones.
+    /// Blank lines are skipped, and the positions in errors count only the
+    /// rows that are kept. The [`universe!`](crate::universe) macro does the
+    /// same at compile time.
+    pub fn from_ascii(s: &str, mode: AsciiParseMode) -> Result<Self, AsciiParseError> {
+        let mut rows = Vec::new();
+        for line in s.lines().filter(|line| !line.trim().is_empty()) {
+            let row_index = rows.len();
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(col, ch)| match ch {
+                    '.' | ' ' => Ok(false),
+                    '*' | '#' | 'O' => Ok(true),
+                    ch => Err(AsciiParseError::UnrecognizedCharacter {
+                        row: row_index,
+                        col,
+                        ch,
+                    }),
+                })
+                .collect::<Result<Vec<bool>, _>>()?;
+            if let (AsciiParseMode::Strict, Some(expected)) = (mode, rows.first().map(Vec::len)) {
+                if row.len() != expected {
+                    return Err(AsciiParseError::InconsistentRowLength {
+                        row: row_index,
+                        expected,
+                        got: row.len(),
+                    });
+                }
+            }
+            rows.push(row);
+        }
+
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut universe =
+            DynamicUniverse::new(width, rows.len()).map_err(|_| AsciiParseError::EmptyInput)?;
+        for (row, cells) in rows.iter().enumerate() {
+            for (column, &alive) in cells.iter().enumerate() {
+                if alive {
+                    universe.set_cell(row, column, State::Alive);
+                }
+            }
+        }
+        Ok(universe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_glider() {
+        let universe =
+            DynamicUniverse::from_ascii("\n.*.\n..*\n\n***\n", AsciiParseMode::Strict).unwrap();
+        assert_eq!((universe.width(), universe.height()), (3, 3));
+        assert_eq!(universe.count_alive(), 5);
+        assert!(universe.is_alive(0, 1) && universe.is_alive(1, 2));
+        assert!(universe.is_alive(2, 0) && universe.is_alive(2, 2));
+
+        let mixed = DynamicUniverse::from_ascii(" O \n#  ", AsciiParseMode::Strict).unwrap();
+        assert_eq!(mixed.count_alive(), 2);
+    }
+
+    #[test]
+    fn test_lenient_pads_short_rows() {
+        let universe = DynamicUniverse::from_ascii("#\n..#\n##", AsciiParseMode::Lenient).unwrap();
+        assert_eq!((universe.width(), universe.height()), (3, 3));
+        assert!(!universe.is_alive(0, 2));
+        assert_eq!(universe.count_alive(), 4);
+
+        assert_eq!(
+            DynamicUniverse::from_ascii("#\n..#\n##", AsciiParseMode::Strict),
+            Err(AsciiParseError::InconsistentRowLength {
+                row: 1,
+                expected: 1,
+                got: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            DynamicUniverse::from_ascii("\n  \n", AsciiParseMode::Lenient),
+            Err(AsciiParseError::EmptyInput)
+        );
+        assert_eq!(
+            DynamicUniverse::from_ascii("...\n\n.x.", AsciiParseMode::Strict),
+            Err(AsciiParseError::UnrecognizedCharacter {
+                row: 1,
+                col: 1,
+                ch: 'x',
+            })
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Write;
+
+use crate::{Neighborhood, PatternGrid, Universe};
+
+const HEADER: &str = "#Life 1.06";
+
+/// Errors returned when parsing a pattern in Life 1.06 format
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Life106ParseError {
+    /// The first line is not `#Life 1.06`
+    MissingHeader,
+    /// A line does not hold exactly two coordinates
+    InvalidLine(String),
+    /// A coordinate is not a valid integer
+    IntParseError,
+    /// The coordinates are spread over more cells than a pattern may hold
+    PatternTooLarge,
+}
+
+impl fmt::Display for Life106ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Life106ParseError::MissingHeader => write!(f, "missing '{}' header", HEADER),
+            Life106ParseError::InvalidLine(line) => write!(f, "invalid line '{}'", line),
+            Life106ParseError::IntParseError => write!(f, "invalid coordinate"),
+            Life106ParseError::PatternTooLarge => write!(f, "pattern is too large"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Life106ParseError {}
+
+/// Parses a pattern in Life 1.06 format
+///
+/// Each line after the header holds the `x y` coordinates of an alive cell.
+/// The coordinates are shifted so the top-left corner of the pattern's
+/// bounding box becomes (0, 0). Other `#` lines are ignored. Patterns whose
+/// bounding box covers more than 2^24 cells are rejected.
+pub fn parse_life106(input: &str) -> Result<PatternGrid, Life106ParseError> {
+    let mut lines = input.lines();
+    if lines.next().map(str::trim) != Some(HEADER) {
+        return Err(Life106ParseError::MissingHeader);
+    }
+
+    let mut coordinates = Vec::new();
+    for line in lines {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        match (fields.next(), fields.next(), fields.next()) {
+            (Some(x), Some(y), None) => {
+                let x: i64 = x.parse().map_err(|_| Life106ParseError::IntParseError)?;
+                let y: i64 = y.parse().map_err(|_| Life106ParseError::IntParseError)?;
+                coordinates.push((x, y));
+            }
+            _ => return Err(Life106ParseError::InvalidLine(line.to_string())),
+        }
+    }
+
+    let (Some(min_x), Some(min_y)) = (
+        coordinates.iter().map(|&(x, _)| x).min(),
+        coordinates.iter().map(|&(_, y)| y).min(),
+    ) else {
+        return Ok(PatternGrid::new(0, 0));
+    };
+    let max_x = coordinates.iter().map(|&(x, _)| x).max().unwrap_or(min_x);
+    let max_y = coordinates.iter().map(|&(_, y)| y).max().unwrap_or(min_y);
+
+    let (Some(width), Some(height)) = (extent(min_x, max_x), extent(min_y, max_y)) else {
+        return Err(Life106ParseError::PatternTooLarge);
+    };
+    if !super::fits_pattern_limit(width, height) {
+        return Err(Life106ParseError::PatternTooLarge);
+    }
+    let mut grid = PatternGrid::new(width, height);
+    for (x, y) in coordinates {
+        grid.cells[(y - min_y) as usize][(x - min_x) as usize] = true;
+    }
+    Ok(grid)
+}
+
+/// Returns the number of cells from `min` to `max` inclusive, or `None` if
+/// that does not fit in a `usize`
+fn extent(min: i64, max: i64) -> Option<usize> {
+    let span = max.checked_sub(min)?;
+    usize::try_from(span).ok()?.checked_add(1)
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Returns the universe in Life 1.06 format
+    ///
+    /// Each alive cell is written as `x y`, where `x` is the column and `y`
+    /// the row.
+    pub fn to_life106(&self) -> String {
+        let mut life106 = String::new();
+        let _ = writeln!(life106, "{}", HEADER);
+        for (row, column) in self.alive_cells() {
+            let _ = writeln!(life106, "{} {}", column, row);
+        }
+        life106
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_parse_normalizes_negative_coordinates() {
+        let grid = parse_life106("#Life 1.06\n0 -1\n1 0\n-1 1\n0 1\n1 1\n").unwrap();
+        assert_eq!((grid.width, grid.height), (3, 3));
+        assert_eq!(
+            grid.cells,
+            vec![
+                vec![false, true, false],
+                vec![false, false, true],
+                vec![true, true, true],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_far_from_origin() {
+        let grid =
+            parse_life106("#Life 1.06\n#D Block\n-5000 9000\n-4999 9000\n-5000 9001\n-4999 9001")
+                .unwrap();
+        assert_eq!((grid.width, grid.height), (2, 2));
+        assert_eq!(grid.count_alive(), 4);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_life106("0 0\n"),
+            Err(Life106ParseError::MissingHeader)
+        );
+        assert_eq!(
+            parse_life106("#Life 1.06\n0 0 0\n"),
+            Err(Life106ParseError::InvalidLine("0 0 0".to_string()))
+        );
+        assert_eq!(
+            parse_life106("#Life 1.06\n0\n"),
+            Err(Life106ParseError::InvalidLine("0".to_string()))
+        );
+        assert_eq!(
+            parse_life106("#Life 1.06\n0 y\n"),
+            Err(Life106ParseError::IntParseError)
+        );
+        assert_eq!(
+            parse_life106("#Life 1.06\n-9223372036854775808 0\n9223372036854775807 0\n"),
+            Err(Life106ParseError::PatternTooLarge)
+        );
+        assert_eq!(
+            parse_life106("#Life 1.06\n0 0\n100000 100000\n"),
+            Err(Life106ParseError::PatternTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_parse_empty_pattern() {
+        let grid = parse_life106("#Life 1.06\n").unwrap();
+        assert_eq!((grid.width, grid.height), (0, 0));
+    }
+
+    #[test]
+    fn test_to_life106_round_trip() {
+        let mut universe = Universe::<6, 6>::new();
+        universe.seed_glider(2, 3).unwrap();
+        let life106 = universe.to_life106();
+        assert_eq!(life106.lines().next(), Some("#Life 1.06"));
+        assert_eq!(life106.lines().nth(1), Some("4 2"));
+
+        let grid = parse_life106(&life106).unwrap();
+        let mut parsed = Universe::<6, 6>::new();
+        parsed.place_pattern(&grid, 2, 3).unwrap();
+        assert_eq!(parsed.state_grid(), universe.state_grid());
+    }
+}
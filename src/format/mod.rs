@@ -0,0 +1,22 @@
+//! Readers and writers for common Life pattern file formats
+
+mod ascii;
+mod life106;
+mod plaintext;
+mod rle;
+
+pub use ascii::{AsciiParseError, AsciiParseMode};
+pub use life106::{parse_life106, Life106ParseError};
+pub use plaintext::{parse_plaintext, PlaintextParseError};
+pub use rle::{parse_rle, RleParseError};
+
+/// The most cells a parsed pattern may hold, so that a corrupt or hostile
+/// file cannot request an enormous allocation
+const MAX_PATTERN_CELLS: usize = 1 << 24;
+
+/// Returns whether a `width` by `height` pattern is small enough to allocate
+fn fits_pattern_limit(width: usize, height: usize) -> bool {
+    width
+        .checked_mul(height)
+        .is_some_and(|cells| cells <= MAX_PATTERN_CELLS)
+}
@@ -0,0 +1,150 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::PatternGrid;
+
+/// Errors returned when parsing a pattern in plaintext (`.cells`) format
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PlaintextParseError {
+    /// The rows do not give the pattern a width, as none of them contains a
+    /// cell; shorter rows are otherwise padded to the longest one
+    InconsistentWidth,
+    /// A character other than `.` or `O` was found in a pattern row
+    UnrecognizedCharacter(char),
+}
+
+impl fmt::Display for PlaintextParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlaintextParseError::InconsistentWidth => {
+                write!(f, "no pattern row contains a cell to set the width")
+            }
+            PlaintextParseError::UnrecognizedCharacter(c) => {
+                write!(f, "unrecognized character '{}'", c)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PlaintextParseError {}
+
+/// Parses a pattern in Golly's plaintext format
+///
+/// Lines starting with `!` are comments, and `!Name:` sets the pattern name.
+/// A line holding nothing but `!` ends the pattern, and anything after it is
+/// ignored. `.` is a dead cell and `O` an alive one. Rows shorter than the
+/// longest row are padded with dead cells.
+pub fn parse_plaintext(input: &str) -> Result<PatternGrid, PlaintextParseError> {
+    let mut name = None;
+    let mut rows = Vec::new();
+    for line in input.lines() {
+        if line.trim_end() == "!" {
+            break;
+        }
+        if let Some(comment) = line.strip_prefix('!') {
+            if let Some(pattern_name) = comment.strip_prefix("Name:") {
+                name = Some(pattern_name.trim().to_string());
+            }
+            continue;
+        }
+        let row = line
+            .trim_end()
+            .chars()
+            .map(|c| match c {
+                '.' => Ok(false),
+                'O' => Ok(true),
+                c => Err(PlaintextParseError::UnrecognizedCharacter(c)),
+            })
+            .collect::<Result<Vec<bool>, _>>()?;
+        rows.push(row);
+    }
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    if width == 0 {
+        return Err(PlaintextParseError::InconsistentWidth);
+    }
+    for row in rows.iter_mut() {
+        row.resize(width, false);
+    }
+    Ok(PatternGrid {
+        width,
+        height: rows.len(),
+        cells: rows,
+        rule: None,
+        name,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_parse_block() {
+        let grid = parse_plaintext("!Name: Block\nOO\nOO\n!\n").unwrap();
+        assert_eq!(grid.width, 2);
+        assert_eq!(grid.height, 2);
+        assert_eq!(grid.name.as_deref(), Some("Block"));
+        assert_eq!(grid.cells, vec![vec![true, true], vec![true, true]]);
+    }
+
+    #[test]
+    fn test_parse_pads_short_rows() {
+        let grid = parse_plaintext("!Name: Glider\r\n.O\r\n..O  \r\nOOO\r\n").unwrap();
+        assert_eq!(grid.width, 3);
+        assert_eq!(
+            grid.cells,
+            vec![
+                vec![false, true, false],
+                vec![false, false, true],
+                vec![true, true, true],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_keeps_blank_rows() {
+        let grid = parse_plaintext("O\n\nO").unwrap();
+        assert_eq!(grid.height, 3);
+        assert_eq!(grid.cells[1], vec![false]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_plaintext("OO\nO*\n"),
+            Err(PlaintextParseError::UnrecognizedCharacter('*'))
+        );
+        assert_eq!(
+            parse_plaintext("!Name: Nothing\n"),
+            Err(PlaintextParseError::InconsistentWidth)
+        );
+    }
+
+    #[test]
+    fn test_parse_stops_at_end_marker() {
+        let grid = parse_plaintext("!Name: Dot\r\nO\r\n!\r\nOOO\nnot a pattern\n").unwrap();
+        assert_eq!(grid.name.as_deref(), Some("Dot"));
+        assert_eq!((grid.width, grid.height), (1, 1));
+        assert_eq!(
+            parse_plaintext("!\nOO\n"),
+            Err(PlaintextParseError::InconsistentWidth)
+        );
+    }
+
+    #[test]
+    fn test_plaintext_and_rle_share_pattern_grid() {
+        let plaintext = parse_plaintext(".O\n..O\nOOO").unwrap();
+        let rle = crate::parse_rle("x = 3, y = 3\nbo$2bo$3o!").unwrap();
+        assert_eq!(plaintext.cells, rle.cells);
+
+        let mut universe = crate::Universe::<4, 4>::new();
+        universe.place_pattern(&plaintext, 1, 0).unwrap();
+        assert_eq!(universe.count_alive(), 5);
+        assert!(universe.is_alive(3, 2));
+    }
+}
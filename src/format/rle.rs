@@ -0,0 +1,387 @@
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::fmt::Write;
+
+use crate::{Neighborhood, PatternGrid, Universe};
+
+/// Maximum length of a body line written by `Universe::to_rle()`
+const MAX_LINE_LENGTH: usize = 70;
+
+/// Errors returned when parsing a pattern in RLE format
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RleParseError {
+    /// The `x = W, y = H` header is missing or malformed
+    InvalidHeader,
+    /// The header declares more cells than a pattern may hold
+    PatternTooLarge,
+    /// The body places cells outside the width or height given in the header
+    BodyExceedsHeader,
+    /// A character that is not part of the RLE format was found
+    UnexpectedCharacter(char),
+    /// The pattern is not terminated with `!`
+    MissingTerminator,
+    /// The input ended before the header, or a run count was not followed by a tag
+    TruncatedInput,
+}
+
+impl fmt::Display for RleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RleParseError::InvalidHeader => write!(f, "invalid or missing RLE header"),
+            RleParseError::PatternTooLarge => write!(f, "pattern is too large"),
+            RleParseError::BodyExceedsHeader => {
+                write!(f, "pattern extends past the size given in the header")
+            }
+            RleParseError::UnexpectedCharacter(c) => write!(f, "unexpected character '{}'", c),
+            RleParseError::MissingTerminator => write!(f, "pattern is not terminated with '!'"),
+            RleParseError::TruncatedInput => write!(f, "unexpected end of input"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RleParseError {}
+
+/// Parses a pattern in the run-length encoded format used by Golly and LifeWiki
+///
+/// `#N` lines set the pattern name, other `#` lines are ignored. Rows shorter
+/// than the width given in the header are padded with dead cells. Headers
+/// declaring more than 2^24 cells are rejected.
+pub fn parse_rle(input: &str) -> Result<PatternGrid, RleParseError> {
+    let mut name = None;
+    let mut lines = input.lines();
+    let header = loop {
+        let line = lines.next().ok_or(RleParseError::TruncatedInput)?.trim();
+        if let Some(comment) = line.strip_prefix('#') {
+            if let Some(pattern_name) = comment.strip_prefix('N') {
+                name = Some(pattern_name.trim().to_string());
+            }
+        } else if !line.is_empty() {
+            break line;
+        }
+    };
+
+    let (width, height, rule) = parse_header(header)?;
+    if !super::fits_pattern_limit(width, height) {
+        return Err(RleParseError::PatternTooLarge);
+    }
+    let mut grid = PatternGrid::new(width, height);
+    grid.rule = rule;
+    grid.name = name;
+
+    let (mut row, mut column) = (0usize, 0usize);
+    let mut count: Option<usize> = None;
+    for c in lines.flat_map(str::chars) {
+        match c {
+            '0'..='9' => {
+                let digit = c as usize - '0' as usize;
+                count = Some(count.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+            }
+            'b' | 'o' => {
+                let run = count.take().unwrap_or(1);
+                let end = column
+                    .checked_add(run)
+                    .filter(|&end| row < height && end <= width)
+                    .ok_or(RleParseError::BodyExceedsHeader)?;
+                if c == 'o' {
+                    grid.cells[row][column..end].fill(true);
+                }
+                column = end;
+            }
+            '$' => {
+                row = row
+                    .checked_add(count.take().unwrap_or(1))
+                    .ok_or(RleParseError::BodyExceedsHeader)?;
+                column = 0;
+            }
+            '!' if count.is_some() => return Err(RleParseError::TruncatedInput),
+            '!' => return Ok(grid),
+            c if c.is_whitespace() => {}
+            c => return Err(RleParseError::UnexpectedCharacter(c)),
+        }
+    }
+    Err(RleParseError::MissingTerminator)
+}
+
+/// Parses `x = W, y = H` with an optional `rule = ...`
+fn parse_header(line: &str) -> Result<(usize, usize, Option<String>), RleParseError> {
+    let (mut width, mut height, mut rule) = (None, None, None);
+    for entry in line.split(',') {
+        let (key, value) = entry.split_once('=').ok_or(RleParseError::InvalidHeader)?;
+        let value = value.trim();
+        match key.trim() {
+            "x" => width = value.parse().ok(),
+            "y" => height = value.parse().ok(),
+            "rule" => rule = Some(value.to_string()),
+            _ => {}
+        }
+    }
+    match (width, height) {
+        (Some(width), Some(height)) => Ok((width, height, rule)),
+        _ => Err(RleParseError::InvalidHeader),
+    }
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Returns the universe in RLE format
+    ///
+    /// Trailing dead cells of each row and trailing empty rows are omitted,
+    /// and body lines are wrapped at 70 characters between runs. Once the
+    /// universe has evolved, the generation is recorded in a Golly-style
+    /// `#CXRLE Gen=N` line, which `parse_rle()` skips as a comment.
+    pub fn to_rle(&self) -> String {
+        let mut rle = String::new();
+        if self.generation > 0 {
+            let _ = writeln!(rle, "#CXRLE Gen={}", self.generation);
+        }
+        let _ = writeln!(
+            rle,
+            "x = {}, y = {}, rule = {}",
+            W,
+            H,
+            self.rule.to_bs_string()
+        );
+
+        let mut writer = RleWriter::new(&mut rle);
+        let mut pending_rows = 0;
+        for row in self.grid.iter() {
+            let mut column = 0;
+            while column < W {
+                let alive = row[column].is_alive();
+                let run = row[column..]
+                    .iter()
+                    .take_while(|cell| cell.is_alive() == alive)
+                    .count();
+                column += run;
+                if !alive && column == W {
+                    break;
+                }
+                if pending_rows > 0 {
+                    writer.push(pending_rows, '$');
+                    pending_rows = 0;
+                }
+                writer.push(run, if alive { 'o' } else { 'b' });
+            }
+            pending_rows += 1;
+        }
+        writer.push(1, '!');
+        rle.push('\n');
+        rle
+    }
+}
+
+/// Appends run-length encoded items to a body, wrapping long lines
+struct RleWriter<'a> {
+    output: &'a mut String,
+    line_length: usize,
+}
+
+impl<'a> RleWriter<'a> {
+    fn new(output: &'a mut String) -> Self {
+        RleWriter {
+            output,
+            line_length: 0,
+        }
+    }
+
+    /// Appends a run of `count` tags, omitting the count when it is one
+    fn push(&mut self, count: usize, tag: char) {
+        let mut item = String::new();
+        if count > 1 {
+            let _ = write!(item, "{}", count);
+        }
+        item.push(tag);
+
+        if self.line_length > 0 && self.line_length + item.len() > MAX_LINE_LENGTH {
+            self.output.push('\n');
+            self.line_length = 0;
+        }
+        self.line_length += item.len();
+        self.output.push_str(&item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, vec};
+
+    const GLIDER: &str = "#N Glider\n#C The smallest spaceship\n#O Richard K. Guy\nx = 3, y = 3, rule = B3/S23\nbob$2bo$3o!\n";
+
+    #[test]
+    fn test_parse_glider() {
+        let grid = parse_rle(GLIDER).unwrap();
+        assert_eq!(grid.width, 3);
+        assert_eq!(grid.height, 3);
+        assert_eq!(grid.name.as_deref(), Some("Glider"));
+        assert_eq!(grid.rule.as_deref(), Some("B3/S23"));
+        assert_eq!(
+            grid.cells,
+            vec![
+                vec![false, true, false],
+                vec![false, false, true],
+                vec![true, true, true],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_pads_short_rows() {
+        let grid = parse_rle("x = 4, y = 3\no2$\n3o!").unwrap();
+        assert_eq!(grid.rule, None);
+        assert_eq!(grid.name, None);
+        assert_eq!(
+            grid.cells,
+            vec![
+                vec![true, false, false, false],
+                vec![false; 4],
+                vec![true, true, true, false],
+            ]
+        );
+        assert_eq!(grid.count_alive(), 4);
+    }
+
+    #[test]
+    fn test_parse_multi_line_body() {
+        let grid = parse_rle("x = 12, y = 1\r\n10b\r\n2o!\r\n").unwrap();
+        assert!(grid.cells[0][10] && grid.cells[0][11]);
+        assert_eq!(grid.count_alive(), 2);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse_rle(""), Err(RleParseError::TruncatedInput));
+        assert_eq!(
+            parse_rle("#C only a comment"),
+            Err(RleParseError::TruncatedInput)
+        );
+        assert_eq!(parse_rle("x = 3\nooo!"), Err(RleParseError::InvalidHeader));
+        assert_eq!(
+            parse_rle("y = 3, x = a\no!"),
+            Err(RleParseError::InvalidHeader)
+        );
+        assert_eq!(
+            parse_rle("x = 2, y = 1\n3o!"),
+            Err(RleParseError::BodyExceedsHeader)
+        );
+        assert_eq!(
+            parse_rle("x = 2, y = 1\n$o!"),
+            Err(RleParseError::BodyExceedsHeader)
+        );
+        assert_eq!(
+            parse_rle("x = 3, y = 3\nb99999999999999999999999b!"),
+            Err(RleParseError::BodyExceedsHeader)
+        );
+        assert_eq!(
+            parse_rle("x = 3, y = 3\n$99999999999999999999999$o!"),
+            Err(RleParseError::BodyExceedsHeader)
+        );
+        assert_eq!(
+            parse_rle("x = 100000000, y = 100000000\no!"),
+            Err(RleParseError::PatternTooLarge)
+        );
+        assert_eq!(
+            parse_rle(&format!("x = {}, y = 2\no!", usize::MAX)),
+            Err(RleParseError::PatternTooLarge)
+        );
+        assert_eq!(
+            parse_rle("x = 3, y = 1\nozo!"),
+            Err(RleParseError::UnexpectedCharacter('z'))
+        );
+        assert_eq!(
+            parse_rle("x = 3, y = 1\n3o"),
+            Err(RleParseError::MissingTerminator)
+        );
+        assert_eq!(
+            parse_rle("x = 3, y = 1\no2!"),
+            Err(RleParseError::TruncatedInput)
+        );
+    }
+
+    #[test]
+    fn test_parsed_pattern_can_be_placed() {
+        let grid = parse_rle(GLIDER).unwrap();
+        let mut universe = crate::Universe::<5, 5>::new();
+        universe.place_pattern(&grid, 1, 1).unwrap();
+
+        let mut expected = crate::Universe::<5, 5>::new();
+        expected.seed_glider(1, 1).unwrap();
+        assert_eq!(universe.state_grid(), expected.state_grid());
+    }
+
+    fn round_trip<const W: usize, const H: usize>(universe: &Universe<W, H>) -> Universe<W, H> {
+        let grid = parse_rle(&universe.to_rle()).unwrap();
+        assert_eq!((grid.width, grid.height), (W, H));
+        let mut parsed = Universe::new();
+        parsed.place_pattern(&grid, 0, 0).unwrap();
+        parsed
+    }
+
+    #[test]
+    fn test_to_rle_block() {
+        let mut universe = Universe::<4, 4>::new();
+        universe.seed_block(0, 2).unwrap();
+        assert_eq!(
+            universe.to_rle(),
+            "x = 4, y = 4, rule = B3/S23\n2b2o$2b2o!\n"
+        );
+    }
+
+    #[test]
+    fn test_to_rle_skips_empty_rows() {
+        let mut universe = Universe::<5, 6>::new();
+        universe.seed_glider(3, 0).unwrap();
+        assert_eq!(
+            universe.to_rle(),
+            "x = 5, y = 6, rule = B3/S23\n3$bo$2bo$3o!\n"
+        );
+    }
+
+    #[test]
+    fn test_to_rle_dead_universe() {
+        // Every row is empty, so the body is only the terminator
+        let universe = Universe::<3, 3>::new();
+        assert_eq!(universe.to_rle(), "x = 3, y = 3, rule = B3/S23\n!\n");
+        assert!(round_trip(&universe).is_empty());
+    }
+
+    #[test]
+    fn test_to_rle_records_generation() {
+        let mut universe = Universe::<4, 4>::new();
+        universe.seed_block(1, 1).unwrap();
+        universe.evolve();
+        universe.evolve();
+        let rle = universe.to_rle();
+        assert_eq!(rle.lines().next(), Some("#CXRLE Gen=2"));
+        assert_eq!(round_trip(&universe).state_grid(), universe.state_grid());
+    }
+
+    #[test]
+    fn test_to_rle_uses_rule() {
+        let universe = Universe::<3, 3>::with_rule(crate::RuleSet::HIGH_LIFE);
+        assert!(universe
+            .to_rle()
+            .starts_with("x = 3, y = 3, rule = B36/S23\n"));
+    }
+
+    #[test]
+    fn test_to_rle_round_trip_random() {
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(19);
+        let mut universe = Universe::<20, 20>::new();
+        for row in 0..20 {
+            for column in 0..20 {
+                if rng.gen_bool(0.4) {
+                    universe.set_cell(row, column, crate::State::Alive);
+                }
+            }
+        }
+
+        let rle = universe.to_rle();
+        assert!(rle.lines().all(|line| line.len() <= MAX_LINE_LENGTH));
+        assert!(rle.lines().count() > 2);
+        assert_eq!(round_trip(&universe).state_grid(), universe.state_grid());
+    }
+}
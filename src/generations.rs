@@ -0,0 +1,272 @@
+use crate::{check_bounds, BoundaryCondition, RuleSet};
+
+/// A rule for Generations automata, where cells that fail to survive pass
+/// through dying states before they are dead
+///
+/// `birth[n]` is true if a dead cell with `n` alive neighbors comes alive and
+/// `survival[n]` is true if an alive cell with `n` alive neighbors stays
+/// alive. Dying cells are never counted as neighbors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GenerationsRuleSet {
+    pub birth: [bool; 9],
+    pub survival: [bool; 9],
+}
+
+impl GenerationsRuleSet {
+    /// Star Wars (B2/S345/4), meant for a universe with 4 states
+    pub const STAR_WARS: GenerationsRuleSet = GenerationsRuleSet::from_counts(&[2], &[3, 4, 5]);
+    /// Brian's Brain (B2/S/3), meant for a universe with 3 states
+    pub const BRIANS_BRAIN: GenerationsRuleSet = GenerationsRuleSet::from_counts(&[2], &[]);
+
+    /// Builds a rule from lists of birth and survival neighbor counts
+    ///
+    /// Counts greater than 8 are ignored.
+    pub const fn from_counts(birth: &[u8], survival: &[u8]) -> Self {
+        let rule = RuleSet::from_counts(birth, survival);
+        GenerationsRuleSet {
+            birth: rule.birth,
+            survival: rule.survival,
+        }
+    }
+}
+
+/// Cell of a Generations universe with `N` states
+///
+/// State 0 is dead, 1 is alive and 2 to `N - 1` are the dying states, which a
+/// cell passes through in increasing order before it is dead again.
+///
+/// The state is kept in a whole byte rather than packed into `ceil(log2(N))`
+/// bits: a `modular_bitfield` field needs its width fixed when the struct is
+/// declared, and it cannot be derived from `N`. A byte holds every state for
+/// any `N` up to 255, at most 7 bits more than packing would use.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GenerationsCell<const N: u8> {
+    state: u8,
+}
+
+impl<const N: u8> GenerationsCell<N> {
+    const CHECK_STATES: () = assert!(N >= 2, "a Generations automaton needs at least 2 states");
+
+    /// Creates a dead cell
+    pub const fn new() -> Self {
+        let () = Self::CHECK_STATES;
+        GenerationsCell { state: 0 }
+    }
+
+    /// Returns the state of the cell, from 0 to `N - 1`
+    pub fn state(&self) -> u8 {
+        self.state
+    }
+
+    /// Returns true if the cell is alive, that is in state 1
+    pub fn is_alive(&self) -> bool {
+        self.state == 1
+    }
+
+    /// Returns true if the cell is in one of the dying states
+    pub fn is_dying(&self) -> bool {
+        self.state >= 2
+    }
+
+    /// Sets a new state for the cell based on the current state and alive neighbors
+    fn evolve(&mut self, rule: &GenerationsRuleSet, live_neighbors: u8) {
+        let next = |flags: &[bool; 9]| flags.get(live_neighbors as usize) == Some(&true);
+        self.state = match self.state {
+            0 if next(&rule.birth) => 1,
+            0 => 0,
+            1 if next(&rule.survival) => 1,
+            // Dying cells and alive cells that fail to survive move to the next state
+            state => (state + 1) % N,
+        };
+    }
+}
+
+/// A Generations universe with a fixed width and height and `N` states per
+/// cell
+///
+/// The grid wraps around its edges and neighbors are counted over the Moore
+/// neighborhood.
+pub struct GenerationsUniverse<const W: usize, const H: usize, const N: u8> {
+    grid: [[GenerationsCell<N>; W]; H],
+    grid_back: [[GenerationsCell<N>; W]; H],
+    rule: GenerationsRuleSet,
+}
+
+impl<const W: usize, const H: usize, const N: u8> GenerationsUniverse<W, H, N> {
+    /// Creates an all-dead universe with the given rule
+    pub const fn new(rule: GenerationsRuleSet) -> Self {
+        GenerationsUniverse {
+            grid: [[GenerationsCell::new(); W]; H],
+            grid_back: [[GenerationsCell::new(); W]; H],
+            rule,
+        }
+    }
+
+    /// Returns the reference to the grid
+    pub fn grid(&self) -> &[[GenerationsCell<N>; W]; H] {
+        &self.grid
+    }
+
+    /// Returns the rule the universe evolves by
+    pub fn rule(&self) -> &GenerationsRuleSet {
+        &self.rule
+    }
+
+    /// Sets the state of the cell
+    ///
+    /// Panics if the cell is out of bounds or the state is not less than `N`.
+    pub fn set_cell(&mut self, row: usize, column: usize, state: u8) {
+        check_bounds::<W, H>(row, column);
+        assert!(
+            state < N,
+            "state {} is out of range for a {}-state universe",
+            state,
+            N
+        );
+        self.grid[row][column].state = state;
+    }
+
+    /// Returns the state of the cell
+    ///
+    /// Panics if the cell is out of bounds.
+    pub fn state(&self, row: usize, column: usize) -> u8 {
+        check_bounds::<W, H>(row, column);
+        self.grid[row][column].state()
+    }
+
+    /// Returns the number of alive cells, not counting dying ones
+    pub fn count_alive(&self) -> usize {
+        self.grid.iter().flatten().filter(|c| c.is_alive()).count()
+    }
+
+    /// Evolves the universe
+    pub fn evolve(&mut self) {
+        for row in 0..H {
+            for column in 0..W {
+                let mut cell = self.grid[row][column];
+                cell.evolve(&self.rule, self.live_neighbor_count(row, column));
+                self.grid_back[row][column] = cell;
+            }
+        }
+
+        core::mem::swap(&mut self.grid, &mut self.grid_back);
+    }
+
+    /// Counts the alive neighbors of a cell, wrapping around the edges
+    fn live_neighbor_count(&self, row: usize, column: usize) -> u8 {
+        let mut count = 0;
+        BoundaryCondition::Toroidal.for_each_moore_neighbor(row, column, H, W, |row, column| {
+            count += self.grid[row][column].is_alive() as u8;
+        });
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lonely_cell_dies_through_every_state() {
+        let mut universe = GenerationsUniverse::<5, 5, 4>::new(GenerationsRuleSet::STAR_WARS);
+        universe.set_cell(2, 2, 1);
+        for expected in [2, 3, 0] {
+            universe.evolve();
+            assert_eq!(universe.state(2, 2), expected);
+        }
+
+        let mut universe = GenerationsUniverse::<5, 5, 7>::new(GenerationsRuleSet::STAR_WARS);
+        universe.set_cell(2, 2, 1);
+        for _ in 0..5 {
+            universe.evolve();
+            assert!(universe.grid()[2][2].is_dying());
+        }
+        universe.evolve();
+        assert_eq!(universe.state(2, 2), 0);
+    }
+
+    #[test]
+    fn test_birth_starts_alive() {
+        let mut universe = GenerationsUniverse::<5, 5, 4>::new(GenerationsRuleSet::STAR_WARS);
+        universe.set_cell(1, 1, 1);
+        universe.set_cell(1, 3, 1);
+        universe.evolve();
+        assert_eq!(universe.state(2, 2), 1);
+        assert_eq!(universe.state(1, 2), 1);
+        assert_eq!(universe.state(1, 1), 2);
+    }
+
+    #[test]
+    fn test_dying_cells_are_not_counted() {
+        let mut universe = GenerationsUniverse::<5, 5, 4>::new(GenerationsRuleSet::STAR_WARS);
+        universe.set_cell(1, 1, 2);
+        universe.set_cell(1, 3, 3);
+        universe.evolve();
+        assert_eq!(universe.state(2, 2), 0);
+        assert_eq!(universe.state(1, 1), 3);
+        assert_eq!(universe.state(1, 3), 0);
+    }
+
+    #[test]
+    fn test_star_wars_spaceship_moves_one_cell_per_generation() {
+        let mut universe = GenerationsUniverse::<10, 6, 4>::new(GenerationsRuleSet::STAR_WARS);
+        for row in [2, 3] {
+            for (column, state) in [(1, 3), (2, 2), (3, 1)] {
+                universe.set_cell(row, column, state);
+            }
+        }
+        for generation in 1..=4 {
+            universe.evolve();
+            for row in 0..6 {
+                for column in 0..10usize {
+                    let expected = match (row, column.checked_sub(generation)) {
+                        (2 | 3, Some(offset @ 1..=3)) => 4 - offset as u8,
+                        _ => 0,
+                    };
+                    assert_eq!(universe.state(row, column), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_brians_brain_matches_brain_universe() {
+        use crate::{BrainState, BrainUniverse};
+
+        let mut generations = GenerationsUniverse::<8, 6, 3>::new(GenerationsRuleSet::BRIANS_BRAIN);
+        let mut brain = BrainUniverse::<8, 6>::new();
+        for (row, column, state) in [(2, 1, 2), (3, 1, 2), (2, 2, 1), (3, 2, 1), (0, 6, 1)] {
+            generations.set_cell(row, column, state);
+            let state = if state == 1 {
+                BrainState::On
+            } else {
+                BrainState::Dying
+            };
+            brain.set_cell(row, column, state);
+        }
+        for _ in 0..10 {
+            generations.evolve();
+            brain.evolve();
+            for row in 0..6 {
+                for column in 0..8 {
+                    assert_eq!(
+                        generations.state(row, column),
+                        brain.state(row, column) as u8
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "state 4 is out of range for a 4-state universe")]
+    fn test_set_cell_rejects_invalid_state() {
+        GenerationsUniverse::<5, 5, 4>::new(GenerationsRuleSet::STAR_WARS).set_cell(0, 0, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "cell (3, 1) is out of bounds for a 4x3 universe")]
+    fn test_set_cell_out_of_bounds() {
+        GenerationsUniverse::<4, 3, 4>::new(GenerationsRuleSet::STAR_WARS).set_cell(3, 1, 0);
+    }
+}
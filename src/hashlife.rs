@@ -0,0 +1,444 @@
+use std::collections::HashMap;
+use std::vec;
+use std::vec::Vec;
+
+use crate::{State, Universe};
+
+/// Index of a node in the arena
+type NodeId = u32;
+
+const DEAD: NodeId = 0;
+const ALIVE: NodeId = 1;
+
+/// Levels above this would overflow the `i64` coordinates
+const MAX_LEVEL: u8 = 60;
+
+/// A square of `2^level` cells, split into four quadrants of `level - 1`
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    /// `[nw, ne, sw, se]`, unused for the two level 0 leaves
+    children: [NodeId; 4],
+    level: u8,
+    population: u64,
+}
+
+/// An unbounded universe evolved with Bill Gosper's Hashlife algorithm
+///
+/// The plane is a quadtree of hash-consed nodes, so identical regions are
+/// stored once, and the future of every node is memoized. Repetitive
+/// patterns can therefore be advanced by huge numbers of generations at once
+/// with `step_k()`.
+///
+/// Like `SparseUniverse`, cells are addressed by `(x, y)` with `x` the column
+/// and `y` the row, and cells never wrap around. Only Conway's rule is
+/// supported.
+#[derive(Clone, Debug)]
+pub struct HashlifeUniverse {
+    nodes: Vec<Node>,
+    /// Canonical node for each `[nw, ne, sw, se]`
+    index: HashMap<[NodeId; 4], NodeId>,
+    /// Memoized `successor(node, log2 of step)`
+    results: HashMap<(NodeId, u8), NodeId>,
+    /// The empty node of each level
+    empty: Vec<NodeId>,
+    root: NodeId,
+    /// `(x, y)` of the root's top-left cell
+    origin: (i64, i64),
+    generation: u64,
+}
+
+impl HashlifeUniverse {
+    /// Creates an empty universe
+    pub fn new() -> Self {
+        let leaf = |population| Node {
+            children: [DEAD; 4],
+            level: 0,
+            population,
+        };
+        let mut universe = HashlifeUniverse {
+            nodes: vec![leaf(0), leaf(1)],
+            index: HashMap::new(),
+            results: HashMap::new(),
+            empty: vec![DEAD],
+            root: DEAD,
+            origin: (0, 0),
+            generation: 0,
+        };
+        universe.root = universe.empty_node(3);
+        universe
+    }
+
+    /// Creates a universe holding the alive cells of `universe`, with its
+    /// top-left cell at (0, 0)
+    pub fn from_universe<const W: usize, const H: usize>(universe: &Universe<W, H>) -> Self {
+        let mut hashlife = HashlifeUniverse::new();
+        let mut level = 3;
+        while (1usize << level) < W.max(H) {
+            level += 1;
+        }
+        hashlife.root = hashlife.build(universe, level, 0, 0);
+        hashlife
+    }
+
+    /// Returns the cells in the `W` x `H` rectangle whose top-left cell is (0, 0)
+    ///
+    /// Cells outside the rectangle are dropped.
+    pub fn to_universe<const W: usize, const H: usize>(&self) -> Universe<W, H> {
+        let mut universe = Universe::new();
+        self.for_each_alive(self.root, self.origin, &mut |x, y| {
+            if (0..W as i64).contains(&x) && (0..H as i64).contains(&y) {
+                universe.set_cell(y as usize, x as usize, State::Alive);
+            }
+        });
+        universe
+    }
+
+    /// Returns true if the cell at `(x, y)` is alive
+    pub fn is_alive(&self, x: i64, y: i64) -> bool {
+        let (mut node, mut left, mut top) = (self.root, self.origin.0, self.origin.1);
+        let size = 1i64 << self.nodes[node as usize].level;
+        if x < left || y < top || x >= left + size || y >= top + size {
+            return false;
+        }
+        while self.nodes[node as usize].level > 0 {
+            let half = 1i64 << (self.nodes[node as usize].level - 1);
+            let east = x >= left + half;
+            let south = y >= top + half;
+            left += if east { half } else { 0 };
+            top += if south { half } else { 0 };
+            node = self.nodes[node as usize].children[2 * south as usize + east as usize];
+        }
+        node == ALIVE
+    }
+
+    /// Returns the number of alive cells
+    pub fn alive_count(&self) -> u64 {
+        self.nodes[self.root as usize].population
+    }
+
+    /// Returns the number of generations the universe has advanced
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Advances the universe by `2^k` generations
+    ///
+    /// # Panics
+    ///
+    /// Panics if the universe would grow beyond 2^60 cells across, which
+    /// happens for `k` above 56 at the latest.
+    pub fn step_k(&mut self, k: u32) {
+        // Pad the pattern until it sits in the central sixteenth of a node at
+        // least 2^(k+3) wide, so nothing can escape the result in 2^k steps
+        loop {
+            let root = self.nodes[self.root as usize];
+            let center = self.center(self.root);
+            let inner = self.center(center);
+            if root.level as u32 >= k + 3
+                && self.nodes[inner as usize].population == root.population
+            {
+                break;
+            }
+            self.expand();
+        }
+        let level = self.nodes[self.root as usize].level;
+        self.root = self.successor(self.root, k as u8);
+        let quarter = 1i64 << (level - 2);
+        self.origin = (self.origin.0 + quarter, self.origin.1 + quarter);
+        self.generation += 1 << k;
+    }
+
+    /// Returns the canonical node with the given quadrants
+    fn join(&mut self, children: [NodeId; 4]) -> NodeId {
+        if let Some(&id) = self.index.get(&children) {
+            return id;
+        }
+        let level = self.nodes[children[0] as usize].level + 1;
+        assert!(level <= MAX_LEVEL, "hashlife universe is too large");
+        let population = children
+            .iter()
+            .map(|&child| self.nodes[child as usize].population)
+            .sum();
+        let id = self.nodes.len() as NodeId;
+        self.nodes.push(Node {
+            children,
+            level,
+            population,
+        });
+        self.index.insert(children, id);
+        id
+    }
+
+    fn empty_node(&mut self, level: u8) -> NodeId {
+        while self.empty.len() <= level as usize {
+            let below = *self.empty.last().unwrap_or(&DEAD);
+            let node = self.join([below; 4]);
+            self.empty.push(node);
+        }
+        self.empty[level as usize]
+    }
+
+    /// Returns the level `level - 1` node at the center of a node
+    fn center(&mut self, node: NodeId) -> NodeId {
+        let [nw, ne, sw, se] = self.children(node);
+        let children = [
+            self.children(nw)[3],
+            self.children(ne)[2],
+            self.children(sw)[1],
+            self.children(se)[0],
+        ];
+        self.join(children)
+    }
+
+    fn children(&self, node: NodeId) -> [NodeId; 4] {
+        self.nodes[node as usize].children
+    }
+
+    /// Doubles the root's size, keeping its contents in the center
+    fn expand(&mut self) {
+        let level = self.nodes[self.root as usize].level;
+        let empty = self.empty_node(level - 1);
+        let [nw, ne, sw, se] = self.children(self.root);
+        let children = [
+            self.join([empty, empty, empty, nw]),
+            self.join([empty, empty, ne, empty]),
+            self.join([empty, sw, empty, empty]),
+            self.join([se, empty, empty, empty]),
+        ];
+        self.root = self.join(children);
+        let half = 1i64 << (level - 1);
+        self.origin = (self.origin.0 - half, self.origin.1 - half);
+    }
+
+    /// Returns the center of `node`, one level down, `2^step_log` generations
+    /// later
+    ///
+    /// `step_log` must be at most `level - 2`.
+    fn successor(&mut self, node: NodeId, step_log: u8) -> NodeId {
+        let Node {
+            children,
+            level,
+            population,
+        } = self.nodes[node as usize];
+        if population == 0 {
+            return self.empty_node(level - 1);
+        }
+        if level == 2 {
+            return self.step_base(node);
+        }
+        if let Some(&result) = self.results.get(&(node, step_log)) {
+            return result;
+        }
+
+        let [nw, ne, sw, se] = children;
+        let [_, nw_ne, nw_sw, nw_se] = self.children(nw);
+        let [ne_nw, _, ne_sw, ne_se] = self.children(ne);
+        let [sw_nw, sw_ne, _, sw_se] = self.children(sw);
+        let [se_nw, se_ne, se_sw, _] = self.children(se);
+        let overlapping = [
+            nw,
+            self.join([nw_ne, ne_nw, nw_se, ne_sw]),
+            ne,
+            self.join([nw_sw, nw_se, sw_nw, sw_ne]),
+            self.join([nw_se, ne_sw, sw_ne, se_nw]),
+            self.join([ne_sw, ne_se, se_nw, se_ne]),
+            sw,
+            self.join([sw_ne, se_nw, sw_se, se_sw]),
+            se,
+        ];
+
+        // A full step spends half the time on each of two rounds, a shorter
+        // one only spends it on the second round
+        let full_step = step_log == level - 2;
+        let inner_step = if full_step { step_log - 1 } else { step_log };
+        let mut parts = [DEAD; 9];
+        for (part, &sub) in parts.iter_mut().zip(overlapping.iter()) {
+            *part = if full_step {
+                self.successor(sub, inner_step)
+            } else {
+                self.center(sub)
+            };
+        }
+        let [p00, p01, p02, p10, p11, p12, p20, p21, p22] = parts;
+        let mut quadrants = [DEAD; 4];
+        for (quadrant, children) in quadrants.iter_mut().zip([
+            [p00, p01, p10, p11],
+            [p01, p02, p11, p12],
+            [p10, p11, p20, p21],
+            [p11, p12, p21, p22],
+        ]) {
+            let joined = self.join(children);
+            *quadrant = self.successor(joined, inner_step);
+        }
+        let result = self.join(quadrants);
+        self.results.insert((node, step_log), result);
+        result
+    }
+
+    /// Advances the center 2x2 of a level 2 node by one generation
+    fn step_base(&mut self, node: NodeId) -> NodeId {
+        let mut cells = [[false; 4]; 4];
+        for (quadrant, &child) in self.children(node).iter().enumerate() {
+            for (leaf, &cell) in self.children(child).iter().enumerate() {
+                let row = quadrant / 2 * 2 + leaf / 2;
+                let column = quadrant % 2 * 2 + leaf % 2;
+                cells[row][column] = cell == ALIVE;
+            }
+        }
+        let mut next = [DEAD; 4];
+        for (leaf, next) in next.iter_mut().enumerate() {
+            let (row, column) = (1 + leaf / 2, 1 + leaf % 2);
+            let neighbors = cells[row - 1..=row + 1]
+                .iter()
+                .flat_map(|cells| &cells[column - 1..=column + 1])
+                .filter(|&&alive| alive)
+                .count()
+                - cells[row][column] as usize;
+            let alive = neighbors == 3 || (neighbors == 2 && cells[row][column]);
+            *next = if alive { ALIVE } else { DEAD };
+        }
+        self.join(next)
+    }
+
+    /// Builds the node of the given level whose top-left cell is `(row, column)`
+    fn build<const W: usize, const H: usize>(
+        &mut self,
+        universe: &Universe<W, H>,
+        level: u8,
+        row: usize,
+        column: usize,
+    ) -> NodeId {
+        if row >= H || column >= W {
+            return self.empty_node(level);
+        }
+        if level == 0 {
+            return if universe.is_alive(row, column) {
+                ALIVE
+            } else {
+                DEAD
+            };
+        }
+        let half = 1 << (level - 1);
+        let children = [
+            self.build(universe, level - 1, row, column),
+            self.build(universe, level - 1, row, column + half),
+            self.build(universe, level - 1, row + half, column),
+            self.build(universe, level - 1, row + half, column + half),
+        ];
+        self.join(children)
+    }
+
+    /// Calls `f(x, y)` for every alive cell of `node`, whose top-left cell is
+    /// `(left, top)`
+    fn for_each_alive(&self, node: NodeId, (left, top): (i64, i64), f: &mut impl FnMut(i64, i64)) {
+        let Node {
+            children,
+            level,
+            population,
+        } = self.nodes[node as usize];
+        if population == 0 {
+            return;
+        }
+        if level == 0 {
+            f(left, top);
+            return;
+        }
+        let half = 1i64 << (level - 1);
+        for (quadrant, &child) in children.iter().enumerate() {
+            let x = left + (quadrant % 2) as i64 * half;
+            let y = top + (quadrant / 2) as i64 * half;
+            self.for_each_alive(child, (x, y), f);
+        }
+    }
+}
+
+impl Default for HashlifeUniverse {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SparseUniverse;
+
+    fn assert_matches_sparse(hashlife: &HashlifeUniverse, sparse: &SparseUniverse) {
+        assert_eq!(hashlife.alive_count(), sparse.alive_count() as u64);
+        for (x, y) in sparse.alive_cells() {
+            assert!(hashlife.is_alive(x, y), "({}, {}) should be alive", x, y);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut universe = Universe::<20, 12>::new();
+        universe.seed_glider(3, 15).unwrap();
+        universe.seed_block(9, 1).unwrap();
+        let hashlife = HashlifeUniverse::from_universe(&universe);
+        assert_eq!(hashlife.alive_count(), 9);
+        assert!(hashlife.is_alive(16, 3));
+        assert!(!hashlife.is_alive(15, 3));
+        assert!(!hashlife.is_alive(-1, 3));
+        assert_eq!(hashlife.to_universe::<20, 12>(), universe);
+    }
+
+    #[test]
+    fn test_blinker_single_steps() {
+        let mut universe = Universe::<5, 5>::new();
+        universe.seed_blinker(2, 1).unwrap();
+        let mut hashlife = HashlifeUniverse::from_universe(&universe);
+        hashlife.step_k(0);
+        assert_eq!(hashlife.generation(), 1);
+        assert!(hashlife.is_alive(2, 1) && hashlife.is_alive(2, 2) && hashlife.is_alive(2, 3));
+        hashlife.step_k(0);
+        assert_eq!(hashlife.to_universe::<5, 5>(), universe);
+    }
+
+    #[test]
+    fn test_glider_matches_sparse() {
+        let mut universe = Universe::<8, 8>::new();
+        universe.seed_glider(0, 0).unwrap();
+        let mut hashlife = HashlifeUniverse::from_universe(&universe);
+        let mut sparse = SparseUniverse::from(universe);
+        for k in 0..6 {
+            hashlife.step_k(k);
+            for _ in 0..1 << k {
+                sparse.evolve();
+            }
+            assert_matches_sparse(&hashlife, &sparse);
+        }
+        assert_eq!(hashlife.generation(), 63);
+    }
+
+    #[test]
+    fn test_gosper_glider_gun_1024_generations() {
+        let mut universe = Universe::<40, 12>::new();
+        universe.seed_gosper_glider_gun(1, 1).unwrap();
+        let mut hashlife = HashlifeUniverse::from_universe(&universe);
+        hashlife.step_k(10);
+        assert_eq!(hashlife.generation(), 1024);
+
+        let mut sparse = SparseUniverse::from(universe);
+        for _ in 0..1024 {
+            sparse.evolve();
+        }
+        assert_matches_sparse(&hashlife, &sparse);
+        // The gun fires a glider every 30 generations
+        assert_eq!(hashlife.alive_count(), 221);
+    }
+
+    #[test]
+    fn test_glider_long_jump() {
+        let mut universe = Universe::<3, 3>::new();
+        universe.seed_glider(0, 0).unwrap();
+        let mut hashlife = HashlifeUniverse::from_universe(&universe);
+        hashlife.step_k(16);
+        // A glider moves one cell diagonally every four generations
+        let shift = (1 << 16) / 4;
+        assert_eq!(hashlife.alive_count(), 5);
+        for (row, column) in universe.alive_cells() {
+            assert!(hashlife.is_alive(column as i64 + shift, row as i64 + shift));
+        }
+    }
+}
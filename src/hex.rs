@@ -0,0 +1,223 @@
+use crate::{Cell, RuleSet, State};
+
+/// A universe of hexagonal cells, each with six neighbors
+///
+/// Cells use "odd-r" offset coordinates: every odd row is shifted half a
+/// cell to the right, so each row sits between the two rows around it. Both
+/// axes wrap around, which requires an even height for the shift to line up
+/// across the top and bottom edges.
+#[derive(Clone)]
+pub struct HexUniverse<const W: usize, const H: usize> {
+    grid: [[Cell; W]; H],
+    grid_back: [[Cell; W]; H],
+    rule: RuleSet,
+}
+
+impl<const W: usize, const H: usize> HexUniverse<W, H> {
+    const CHECK_HEIGHT: () = assert!(H.is_multiple_of(2), "HexUniverse height must be even");
+
+    /// Column offsets of the neighbors in the rows above and below, for
+    /// even and odd rows
+    const ADJACENT_ROW_OFFSETS: [[isize; 2]; 2] = [[-1, 0], [0, 1]];
+
+    /// Creates an empty universe that evolves by B2/S34H
+    pub const fn new() -> Self {
+        Self::with_rule(RuleSet::HEX_LIFE)
+    }
+
+    /// Creates an empty universe that evolves according to the given rule
+    pub const fn with_rule(rule: RuleSet) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CHECK_HEIGHT;
+        HexUniverse {
+            grid: [[Cell::new(); W]; H],
+            grid_back: [[Cell::new(); W]; H],
+            rule,
+        }
+    }
+
+    /// Returns the rule the universe evolves by
+    pub fn rule(&self) -> RuleSet {
+        self.rule
+    }
+
+    /// Sets the state of the cell
+    pub fn set_cell(&mut self, row: usize, column: usize, state: State) {
+        Self::check_bounds(row, column);
+        self.grid[row][column].set_state(state);
+    }
+
+    /// Returns true if the cell at the given position is alive
+    pub fn is_alive(&self, row: usize, column: usize) -> bool {
+        Self::check_bounds(row, column);
+        self.grid[row][column].is_alive()
+    }
+
+    /// Returns the number of alive cells
+    pub fn count_alive(&self) -> usize {
+        self.grid
+            .iter()
+            .flatten()
+            .filter(|cell| cell.is_alive())
+            .count()
+    }
+
+    /// Evolves the universe
+    pub fn evolve(&mut self) {
+        for row in 0..H {
+            for column in 0..W {
+                let mut cell = self.grid[row][column];
+                cell.set_live_neighbors(self.live_neighbor_count_hex(row, column));
+                cell.evolve(&self.rule);
+                self.grid_back[row][column] = cell;
+            }
+        }
+
+        core::mem::swap(&mut self.grid, &mut self.grid_back);
+    }
+
+    /// Returns the number of live cells among the six neighbors of a cell
+    ///
+    /// Besides the cells to the left and right, a cell on an even row
+    /// touches columns `column - 1` and `column` of the rows above and
+    /// below, and a cell on an odd row touches `column` and `column + 1`.
+    pub fn live_neighbor_count_hex(&self, row: usize, column: usize) -> u8 {
+        Self::check_bounds(row, column);
+        let wrap = |index: usize, delta: isize, len: usize| {
+            (index as isize + delta).rem_euclid(len as isize) as usize
+        };
+        let mut count = 0;
+        for delta_col in [-1, 1] {
+            count += self.grid[row][wrap(column, delta_col, W)].state() as u8;
+        }
+        for delta_row in [-1, 1] {
+            let neighbor_row = wrap(row, delta_row, H);
+            for delta_col in Self::ADJACENT_ROW_OFFSETS[row % 2] {
+                count += self.grid[neighbor_row][wrap(column, delta_col, W)].state() as u8;
+            }
+        }
+        count
+    }
+
+    fn check_bounds(row: usize, column: usize) {
+        assert!(
+            row < H && column < W,
+            "cell ({}, {}) is out of bounds for a {}x{} universe",
+            row,
+            column,
+            W,
+            H
+        );
+    }
+}
+
+impl<const W: usize, const H: usize> Default for HexUniverse<W, H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sets the cells at the given axial coordinates `(q, r)`, converting
+    /// them to odd-r offset coordinates
+    fn set_axial<const W: usize, const H: usize>(
+        universe: &mut HexUniverse<W, H>,
+        cells: &[(usize, usize)],
+    ) {
+        for &(q, r) in cells {
+            universe.set_cell(r, q + (r - (r & 1)) / 2, State::Alive);
+        }
+    }
+
+    fn alive<const W: usize, const H: usize>(universe: &HexUniverse<W, H>) -> [[bool; W]; H] {
+        universe.grid.map(|row| row.map(|cell| cell.is_alive()))
+    }
+
+    #[test]
+    fn test_neighbors_in_full_grid() {
+        let mut universe = HexUniverse::<6, 6>::new();
+        for row in 0..6 {
+            for column in 0..6 {
+                universe.set_cell(row, column, State::Alive);
+            }
+        }
+        assert_eq!(universe.live_neighbor_count_hex(2, 3), 6);
+        assert_eq!(universe.live_neighbor_count_hex(3, 3), 6);
+        assert_eq!(universe.live_neighbor_count_hex(0, 0), 6);
+        assert_eq!(universe.live_neighbor_count_hex(5, 5), 6);
+    }
+
+    #[test]
+    fn test_neighbors_depend_on_row_parity() {
+        let count_with = |alive: &[(usize, usize)], row, column| {
+            let mut universe = HexUniverse::<6, 6>::new();
+            for &(r, c) in alive {
+                universe.set_cell(r, c, State::Alive);
+            }
+            universe.live_neighbor_count_hex(row, column)
+        };
+
+        // Row 2 is even, so it touches columns 1 and 2 of rows 1 and 3
+        let even = [(1, 1), (1, 2), (2, 1), (2, 3), (3, 1), (3, 2)];
+        assert_eq!(count_with(&even, 2, 2), 6);
+        assert_eq!(count_with(&[(1, 3), (3, 3)], 2, 2), 0);
+
+        // Row 3 is odd, so it touches columns 2 and 3 of rows 2 and 4
+        let odd = [(2, 2), (2, 3), (3, 1), (3, 3), (4, 2), (4, 3)];
+        assert_eq!(count_with(&odd, 3, 2), 6);
+        assert_eq!(count_with(&[(2, 1), (4, 1)], 3, 2), 0);
+    }
+
+    #[test]
+    fn test_neighbors_wrap() {
+        let mut universe = HexUniverse::<4, 4>::new();
+        universe.set_cell(3, 0, State::Alive);
+        universe.set_cell(0, 3, State::Alive);
+        universe.set_cell(1, 0, State::Alive);
+        // (0, 0) is on an even row: it touches (3, 3), (3, 0), (0, 3),
+        // (0, 1), (1, 3) and (1, 0)
+        assert_eq!(universe.live_neighbor_count_hex(0, 0), 3);
+    }
+
+    #[test]
+    fn test_glider_moves() {
+        // B2/S34H has no glider small enough for a unit test, but under
+        // B24/S245H this six-cell glider moves one cell every three generations
+        const GLIDER: [(usize, usize); 6] = [(0, 0), (0, 2), (1, 0), (1, 1), (2, 1), (2, 2)];
+        let rule = RuleSet::from_counts(&[2, 4], &[2, 4, 5]);
+        let at = |q: usize, r: usize| {
+            let mut universe = HexUniverse::<16, 16>::with_rule(rule);
+            let cells = GLIDER.map(|(dq, dr)| (q + dq, r + dr));
+            set_axial(&mut universe, &cells);
+            universe
+        };
+
+        let mut universe = at(4, 6);
+        for _ in 0..3 {
+            universe.evolve();
+        }
+        assert_eq!(alive(&universe), alive(&at(5, 5)));
+        for _ in 0..3 {
+            universe.evolve();
+        }
+        assert_eq!(alive(&universe), alive(&at(6, 4)));
+        assert_eq!(universe.count_alive(), 6);
+    }
+
+    #[test]
+    fn test_domino_population_is_stable() {
+        // B2/S34H has no small still lifes; the two-cell domino oscillates
+        // with period 2, but its population never changes
+        let mut universe = HexUniverse::<8, 8>::new();
+        set_axial(&mut universe, &[(3, 3), (4, 3)]);
+        let start = alive(&universe);
+        for generation in 1..=6 {
+            universe.evolve();
+            assert_eq!(universe.count_alive(), 2);
+            assert_eq!(alive(&universe) == start, generation % 2 == 0);
+        }
+    }
+}
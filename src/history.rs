@@ -0,0 +1,119 @@
+use alloc::collections::VecDeque;
+
+use crate::{Neighborhood, Universe};
+
+/// Population counts recorded after each `evolve()`, oldest first
+#[derive(Clone, Debug)]
+pub(crate) struct PopulationHistory {
+    counts: VecDeque<u64>,
+    capacity: usize,
+}
+
+impl PopulationHistory {
+    /// Appends a count, evicting the oldest one once `capacity` is reached
+    fn record(&mut self, count: u64) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.counts.len() == self.capacity {
+            self.counts.pop_front();
+        }
+        self.counts.push_back(count);
+    }
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Starts recording the number of alive cells after each `evolve()`
+    ///
+    /// Only the last `capacity` counts are kept. Any previously recorded
+    /// history is discarded.
+    pub fn enable_history(&mut self, capacity: usize) {
+        self.history = Some(PopulationHistory {
+            counts: VecDeque::with_capacity(capacity),
+            capacity,
+        });
+    }
+
+    /// Stops recording and frees the recorded history
+    pub fn disable_history(&mut self) {
+        self.history = None;
+    }
+
+    /// Returns the recorded population counts, oldest first, or `None` if
+    /// history is disabled
+    pub fn history(&self) -> Option<&VecDeque<u64>> {
+        self.history.as_ref().map(|history| &history.counts)
+    }
+
+    /// Records the current population if history is enabled
+    pub(crate) fn record_history(&mut self) {
+        if self.history.is_some() {
+            let count = self.count_alive() as u64;
+            if let Some(history) = self.history.as_mut() {
+                history.record(count);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{State, Universe};
+
+    fn blinker() -> Universe<5, 5> {
+        let mut universe = Universe::new();
+        universe.set_cell(2, 1, State::Alive);
+        universe.set_cell(2, 2, State::Alive);
+        universe.set_cell(2, 3, State::Alive);
+        universe
+    }
+
+    #[test]
+    fn test_history_disabled_by_default() {
+        let mut universe = blinker();
+        universe.evolve();
+        assert!(universe.history().is_none());
+    }
+
+    #[test]
+    fn test_history_keeps_last_counts() {
+        let mut universe = blinker();
+        universe.enable_history(6);
+        for _ in 0..10 {
+            universe.evolve();
+        }
+        let history = universe.history().unwrap();
+        assert_eq!(history.len(), 6);
+        assert!(history.iter().all(|&count| count == 3));
+    }
+
+    #[test]
+    fn test_history_evicts_oldest() {
+        let mut universe = Universe::<5, 5>::new();
+        universe.set_cell(0, 0, State::Alive);
+        universe.set_cell(4, 4, State::Alive);
+        universe.enable_history(2);
+        // Lone cells die, so the counts are 0 from the first step on
+        universe.evolve();
+        universe.set_cell(1, 1, State::Alive);
+        universe.set_cell(1, 2, State::Alive);
+        universe.set_cell(2, 1, State::Alive);
+        universe.evolve();
+        universe.evolve();
+        assert!(universe.history().unwrap().iter().eq([4, 4].iter()));
+    }
+
+    #[test]
+    fn test_disable_history() {
+        let mut universe = blinker();
+        universe.enable_history(4);
+        universe.evolve();
+        universe.disable_history();
+        universe.evolve();
+        assert!(universe.history().is_none());
+
+        universe.enable_history(0);
+        universe.evolve();
+        assert_eq!(universe.history().map(|history| history.len()), Some(0));
+    }
+}
@@ -0,0 +1,221 @@
+use modular_bitfield::prelude::*;
+
+use crate::{check_bounds, BoundaryCondition};
+
+/// The state of a cell in the Immigration Game
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BitfieldSpecifier)]
+#[bits = 2]
+pub enum ColorState {
+    Dead = 0,
+    AliveRed = 1,
+    AliveBlue = 2,
+}
+
+impl ColorState {
+    /// Returns true if the cell is alive in either color
+    pub fn is_alive(&self) -> bool {
+        *self != ColorState::Dead
+    }
+}
+
+/// Cell of an Immigration Game universe
+#[bitfield(bits = 16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColorCell {
+    state: ColorState,
+    live_neighbors: B4,
+    red_neighbors: B4,
+    #[skip]
+    __: B6,
+}
+
+impl ColorCell {
+    /// Sets a new state for the cell based on the current state and its
+    /// alive and red neighbors
+    fn evolve(&mut self) {
+        let state = match (self.state(), self.live_neighbors()) {
+            // Three neighbors always have a majority color
+            (ColorState::Dead, 3) if self.red_neighbors() >= 2 => ColorState::AliveRed,
+            (ColorState::Dead, 3) => ColorState::AliveBlue,
+            (state, 2 | 3) => state,
+            _ => ColorState::Dead,
+        };
+        self.set_state(state);
+    }
+}
+
+impl Default for ColorCell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An Immigration Game universe with a fixed width and height
+///
+/// Conway's Life with alive cells colored red or blue. Cells survive as in
+/// Life, whatever the colors of their neighbors, and are born in the color
+/// of the majority of their three parents. The grid wraps around its edges.
+pub struct ImmigrationUniverse<const W: usize, const H: usize> {
+    grid: [[ColorCell; W]; H],
+    grid_back: [[ColorCell; W]; H],
+}
+
+impl<const W: usize, const H: usize> ImmigrationUniverse<W, H> {
+    /// Creates a universe where every cell is dead
+    pub const fn new() -> Self {
+        ImmigrationUniverse {
+            grid: [[ColorCell::new(); W]; H],
+            grid_back: [[ColorCell::new(); W]; H],
+        }
+    }
+
+    /// Returns the reference to the grid
+    pub fn grid(&self) -> &[[ColorCell; W]; H] {
+        &self.grid
+    }
+
+    /// Sets the state of the cell
+    ///
+    /// Panics if the cell is outside the grid.
+    pub fn set_cell_color(&mut self, row: usize, column: usize, state: ColorState) {
+        check_bounds::<W, H>(row, column);
+        self.grid[row][column].set_state(state);
+    }
+
+    /// Returns the state of the cell
+    ///
+    /// Panics if the cell is outside the grid.
+    pub fn state(&self, row: usize, column: usize) -> ColorState {
+        check_bounds::<W, H>(row, column);
+        self.grid[row][column].state()
+    }
+
+    /// Returns the number of red cells
+    pub fn count_red(&self) -> usize {
+        self.count(ColorState::AliveRed)
+    }
+
+    /// Returns the number of blue cells
+    pub fn count_blue(&self) -> usize {
+        self.count(ColorState::AliveBlue)
+    }
+
+    /// Evolves the universe
+    pub fn evolve(&mut self) {
+        for row in 0..H {
+            for column in 0..W {
+                let mut cell = self.grid[row][column];
+                let (live, red) = self.neighbor_counts(row, column);
+                cell.set_live_neighbors(live);
+                cell.set_red_neighbors(red);
+                cell.evolve();
+                self.grid_back[row][column] = cell;
+            }
+        }
+
+        core::mem::swap(&mut self.grid, &mut self.grid_back);
+    }
+
+    fn count(&self, state: ColorState) -> usize {
+        self.grid
+            .iter()
+            .flatten()
+            .filter(|c| c.state() == state)
+            .count()
+    }
+
+    /// Counts the alive and the red neighbors of a cell, wrapping around the
+    /// edges
+    fn neighbor_counts(&self, row: usize, column: usize) -> (u8, u8) {
+        let (mut live, mut red) = (0, 0);
+        BoundaryCondition::Toroidal.for_each_moore_neighbor(row, column, H, W, |row, column| {
+            let state = self.grid[row][column].state();
+            live += state.is_alive() as u8;
+            red += (state == ColorState::AliveRed) as u8;
+        });
+        (live, red)
+    }
+}
+
+impl<const W: usize, const H: usize> Default for ImmigrationUniverse<W, H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{State, Universe};
+
+    fn parents(colors: [ColorState; 3]) -> ImmigrationUniverse<5, 5> {
+        let mut universe = ImmigrationUniverse::new();
+        for (column, color) in colors.into_iter().enumerate() {
+            universe.set_cell_color(1, column + 1, color);
+        }
+        universe
+    }
+
+    #[test]
+    fn test_three_red_parents_give_red_child() {
+        let mut universe = parents([ColorState::AliveRed; 3]);
+        universe.evolve();
+        assert_eq!(universe.state(0, 2), ColorState::AliveRed);
+        assert_eq!(universe.state(2, 2), ColorState::AliveRed);
+        assert_eq!(universe.count_red(), 3);
+        assert_eq!(universe.count_blue(), 0);
+    }
+
+    #[test]
+    fn test_majority_color_wins_birth() {
+        let mut universe = parents([
+            ColorState::AliveRed,
+            ColorState::AliveBlue,
+            ColorState::AliveRed,
+        ]);
+        universe.evolve();
+        assert_eq!(universe.state(0, 2), ColorState::AliveRed);
+        // The survivor keeps its color
+        assert_eq!(universe.state(1, 2), ColorState::AliveBlue);
+
+        let mut universe = parents([
+            ColorState::AliveBlue,
+            ColorState::AliveRed,
+            ColorState::AliveBlue,
+        ]);
+        universe.evolve();
+        assert_eq!(universe.state(2, 2), ColorState::AliveBlue);
+        assert_eq!(universe.count_blue(), 2);
+        assert_eq!(universe.count_red(), 1);
+    }
+
+    #[test]
+    fn test_single_color_pattern_evolves_like_life() {
+        let glider = [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)];
+        let mut immigration = ImmigrationUniverse::<8, 8>::new();
+        let mut life = Universe::<8, 8>::new();
+        for (row, column) in glider {
+            immigration.set_cell_color(row, column, ColorState::AliveBlue);
+            life.set_cell(row, column, State::Alive);
+        }
+        for _ in 0..16 {
+            immigration.evolve();
+            life.evolve();
+            assert_eq!(immigration.count_red(), 0);
+            for row in 0..8 {
+                for column in 0..8 {
+                    assert_eq!(
+                        immigration.state(row, column).is_alive(),
+                        life.is_alive(row, column)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cell (3, 1) is out of bounds for a 4x3 universe")]
+    fn test_set_cell_out_of_bounds() {
+        ImmigrationUniverse::<4, 3>::new().set_cell_color(3, 1, ColorState::AliveRed);
+    }
+}
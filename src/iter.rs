@@ -0,0 +1,356 @@
+use core::iter::FusedIterator;
+
+use crate::Cell;
+
+/// Iterator over every cell of a `Universe` in row-major order
+///
+/// Yields `(row, column, cell)` without copying the grid. Created by
+/// `Universe::cells()`.
+#[derive(Clone, Debug)]
+pub struct CellIter<'a, const W: usize, const H: usize> {
+    grid: &'a [[Cell; W]; H],
+    cursor: (usize, usize),
+}
+
+impl<'a, const W: usize, const H: usize> CellIter<'a, W, H> {
+    pub(crate) fn new(grid: &'a [[Cell; W]; H]) -> Self {
+        CellIter {
+            grid,
+            cursor: (0, 0),
+        }
+    }
+}
+
+impl<const W: usize, const H: usize> Iterator for CellIter<'_, W, H> {
+    type Item = (usize, usize, Cell);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (row, column) = self.cursor;
+        if row >= H || W == 0 {
+            return None;
+        }
+        self.cursor = if column + 1 == W {
+            (row + 1, 0)
+        } else {
+            (row, column + 1)
+        };
+        Some((row, column, self.grid[row][column]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (row, column) = self.cursor;
+        let remaining = if row >= H { 0 } else { (H - row) * W - column };
+        (remaining, Some(remaining))
+    }
+}
+
+impl<const W: usize, const H: usize> ExactSizeIterator for CellIter<'_, W, H> {}
+
+impl<const W: usize, const H: usize> FusedIterator for CellIter<'_, W, H> {}
+
+/// Iterator over the positions of alive cells in row-major order
+///
+/// Yields `(row, column)` for every alive cell. Created by
+/// `Universe::alive_cells()`.
+#[derive(Clone, Debug)]
+pub struct AliveCellIter<'a, const W: usize, const H: usize> {
+    cells: CellIter<'a, W, H>,
+}
+
+impl<'a, const W: usize, const H: usize> AliveCellIter<'a, W, H> {
+    pub(crate) fn new(grid: &'a [[Cell; W]; H]) -> Self {
+        AliveCellIter {
+            cells: CellIter::new(grid),
+        }
+    }
+}
+
+impl<const W: usize, const H: usize> Iterator for AliveCellIter<'_, W, H> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cells
+            .find(|(_, _, cell)| cell.is_alive())
+            .map(|(row, column, _)| (row, column))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.cells.size_hint().1)
+    }
+}
+
+impl<const W: usize, const H: usize> FusedIterator for AliveCellIter<'_, W, H> {}
+
+/// How a cell changed between two generations
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StateChange {
+    /// The cell was dead and is now alive
+    Born,
+    /// The cell was alive and is now dead
+    Died,
+}
+
+/// Iterator over the cells that changed state in the last `evolve()`
+///
+/// Yields `(row, column, change)` in row-major order by comparing the current
+/// grid with the previous generation. Created by `Universe::changed_cells()`.
+#[derive(Clone, Debug)]
+pub struct ChangedCellIter<'a, const W: usize, const H: usize> {
+    current: CellIter<'a, W, H>,
+    previous: CellIter<'a, W, H>,
+}
+
+impl<'a, const W: usize, const H: usize> ChangedCellIter<'a, W, H> {
+    pub(crate) fn new(current: &'a [[Cell; W]; H], previous: &'a [[Cell; W]; H]) -> Self {
+        ChangedCellIter {
+            current: CellIter::new(current),
+            previous: CellIter::new(previous),
+        }
+    }
+}
+
+impl<const W: usize, const H: usize> Iterator for ChangedCellIter<'_, W, H> {
+    type Item = (usize, usize, StateChange);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current.by_ref().zip(self.previous.by_ref()).find_map(
+            |((row, column, now), (_, _, before))| match (before.is_alive(), now.is_alive()) {
+                (false, true) => Some((row, column, StateChange::Born)),
+                (true, false) => Some((row, column, StateChange::Died)),
+                _ => None,
+            },
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.current.size_hint().1)
+    }
+}
+
+impl<const W: usize, const H: usize> FusedIterator for ChangedCellIter<'_, W, H> {}
+
+#[cfg(test)]
+mod tests {
+    use super::StateChange;
+    use crate::{State, Universe};
+
+    #[test]
+    fn test_cells_yields_every_cell() {
+        let universe = Universe::<4, 3>::new();
+        let cells = universe.cells();
+        assert_eq!(cells.len(), 12);
+        assert_eq!(cells.count(), 12);
+    }
+
+    #[test]
+    fn test_cells_row_major_order() {
+        let universe = Universe::<4, 3>::new();
+        let mut expected = (0..3).flat_map(|row| (0..4).map(move |column| (row, column)));
+        for (row, column, _) in universe.cells() {
+            assert_eq!(expected.next(), Some((row, column)));
+        }
+        assert_eq!(expected.next(), None);
+    }
+
+    #[test]
+    fn test_cells_len_decreases() {
+        let universe = Universe::<2, 2>::new();
+        let mut cells = universe.cells();
+        cells.next();
+        assert_eq!(cells.len(), 3);
+        cells.by_ref().for_each(drop);
+        assert_eq!(cells.len(), 0);
+        assert_eq!(cells.next(), None);
+    }
+
+    #[test]
+    fn test_cells_alive_count_matches_manual_count() {
+        let mut universe = Universe::<5, 5>::new();
+        universe.set_cell(0, 1, State::Alive);
+        universe.set_cell(1, 2, State::Alive);
+        universe.set_cell(2, 0, State::Alive);
+        universe.set_cell(4, 4, State::Alive);
+
+        let mut manual = 0;
+        for row in universe.grid() {
+            for cell in row {
+                if cell.is_alive() {
+                    manual += 1;
+                }
+            }
+        }
+
+        let counted = universe.cells().filter(|(_, _, c)| c.is_alive()).count();
+        assert_eq!(counted, manual);
+        assert_eq!(counted, 4);
+    }
+
+    #[test]
+    fn test_alive_cells_fresh_universe() {
+        let universe = Universe::<4, 4>::new();
+        assert_eq!(universe.alive_cells().size_hint(), (0, Some(16)));
+        assert_eq!(universe.alive_cells().count(), 0);
+        assert_eq!(universe.alive_cell_count(), 0);
+    }
+
+    #[test]
+    fn test_alive_cells_full_universe() {
+        let mut universe = Universe::<4, 3>::new();
+        for row in 0..3 {
+            for column in 0..4 {
+                universe.set_cell(row, column, State::Alive);
+            }
+        }
+        assert_eq!(universe.alive_cells().count(), 12);
+        assert_eq!(universe.alive_cell_count(), 12);
+    }
+
+    #[test]
+    fn test_alive_cells_positions_match_set_cell() {
+        let mut universe = Universe::<5, 5>::new();
+        let positions = [(0, 3), (2, 2), (4, 0), (4, 4)];
+        for &(row, column) in positions.iter() {
+            universe.set_cell(row, column, State::Alive);
+        }
+
+        let mut alive = universe.alive_cells();
+        for &position in positions.iter() {
+            assert_eq!(alive.next(), Some(position));
+        }
+        assert_eq!(alive.next(), None);
+        assert_eq!(alive.next(), None);
+        assert_eq!(universe.alive_cell_count(), positions.len());
+    }
+
+    #[test]
+    fn test_changed_cells_blinker() {
+        let mut universe = Universe::<5, 5>::new();
+        universe.set_cell(2, 1, State::Alive);
+        universe.set_cell(2, 2, State::Alive);
+        universe.set_cell(2, 3, State::Alive);
+        universe.evolve();
+
+        let mut changes = universe.changed_cells();
+        assert_eq!(changes.next(), Some((1, 2, StateChange::Born)));
+        assert_eq!(changes.next(), Some((2, 1, StateChange::Died)));
+        assert_eq!(changes.next(), Some((2, 3, StateChange::Died)));
+        assert_eq!(changes.next(), Some((3, 2, StateChange::Born)));
+        assert_eq!(changes.next(), None);
+        assert_eq!(universe.count_changes(), (2, 2));
+    }
+
+    #[test]
+    fn test_changed_cells_block() {
+        let mut universe = Universe::<4, 4>::new();
+        universe.seed_block(1, 1).unwrap();
+        universe.evolve();
+        assert_eq!(universe.changed_cells().count(), 0);
+        assert_eq!(universe.count_changes(), (0, 0));
+    }
+
+    #[test]
+    fn test_changed_cells_before_first_evolve() {
+        let mut universe = Universe::<5, 5>::new();
+        universe.seed_blinker(2, 1).unwrap();
+        assert_eq!(universe.changed_cells().count(), 0);
+        assert_eq!(universe.count_changes(), (0, 0));
+
+        universe.fill(State::Alive);
+        assert_eq!(universe.changed_cells().count(), 0);
+        assert_eq!(universe.count_changes(), (0, 0));
+    }
+
+    #[test]
+    fn test_changed_cells_after_direct_edits() {
+        let mut universe = Universe::<5, 5>::new();
+        universe.seed_blinker(2, 1).unwrap();
+
+        universe.evolve();
+        universe.set_cell(0, 0, State::Alive);
+        assert_eq!(universe.changed_cells().count(), 0);
+        assert_eq!(universe.count_changes(), (0, 0));
+
+        universe.evolve();
+        universe.toggle_cell(4, 4);
+        assert_eq!(universe.changed_cells().count(), 0);
+        assert_eq!(universe.count_changes(), (0, 0));
+
+        universe.evolve();
+        assert_ne!(universe.count_changes(), (0, 0));
+        universe[(4, 0)].set_state(State::Alive);
+        assert_eq!(universe.changed_cells().count(), 0);
+        assert_eq!(universe.count_changes(), (0, 0));
+    }
+
+    #[test]
+    fn test_changed_cells_empty_universe() {
+        let mut universe = Universe::<4, 4>::new();
+        assert_eq!(universe.changed_cells().count(), 0);
+        universe.evolve();
+        assert_eq!(universe.changed_cells().count(), 0);
+        assert_eq!(universe.count_changes(), (0, 0));
+    }
+
+    #[test]
+    fn test_delta_grid() {
+        let mut universe = Universe::<5, 5>::new();
+        universe.seed_blinker(2, 1).unwrap();
+        assert_eq!(universe.delta_grid(), [[None; 5]; 5]);
+        assert_eq!(universe.delta_count(), (0, 0));
+
+        universe.evolve();
+        let delta = universe.delta_grid();
+        assert_eq!(delta.iter().flatten().filter(|c| c.is_some()).count(), 4);
+        assert_eq!(delta[1][2], Some(StateChange::Born));
+        assert_eq!(delta[2][1], Some(StateChange::Died));
+        assert_eq!(delta[2][2], None);
+        assert_eq!(universe.delta_count(), (2, 2));
+        universe.set_cell(0, 0, State::Alive);
+        assert_eq!(universe.delta_grid(), [[None; 5]; 5]);
+
+        let mut empty = Universe::<4, 4>::new();
+        empty.evolve();
+        assert_eq!(empty.delta_grid(), [[None; 4]; 4]);
+
+        let mut block = Universe::<4, 4>::new();
+        block.seed_block(1, 1).unwrap();
+        block.evolve();
+        assert_eq!(block.delta_count(), (0, 0));
+    }
+
+    #[test]
+    fn test_delta_survives_generation_reset() {
+        let mut universe = Universe::<5, 5>::new();
+        universe.seed_blinker(2, 1).unwrap();
+        universe.evolve();
+        universe.reset_generation();
+        assert_eq!(universe.count_changes(), (2, 2));
+        assert_eq!(universe.delta_count(), (2, 2));
+        assert_eq!(universe.delta_grid()[1][2], Some(StateChange::Born));
+    }
+
+    #[test]
+    fn test_delta_is_empty_after_grid_is_replaced() {
+        let mut block = Universe::<4, 4>::new();
+        block.seed_block(1, 1).unwrap();
+        block.step_n(3);
+        let decoded =
+            Universe::<4, 4>::from_bytes(&block.to_bytes::<{ crate::universe_byte_size(4, 4) }>())
+                .unwrap();
+        assert_eq!(decoded.generation(), 3);
+        assert_eq!(decoded.delta_count(), (0, 0));
+        assert_eq!(decoded.delta_grid(), [[None; 4]; 4]);
+
+        let mut blinker = Universe::<5, 5>::new();
+        blinker.seed_blinker(2, 1).unwrap();
+        let snapshot = blinker.save_snapshot();
+        blinker.step_n(2);
+        blinker.evolve();
+        blinker.restore_snapshot(&snapshot);
+        assert_eq!(blinker.delta_count(), (0, 0));
+        blinker.evolve();
+        assert_eq!(blinker.delta_count(), (2, 2));
+        blinker.clear();
+        assert_eq!(blinker.delta_grid(), [[None; 5]; 5]);
+    }
+}
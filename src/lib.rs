@@ -1,9 +1,133 @@
 #![no_std]
+// `#[bitfield]` expands field types inside parentheses in the items it
+// generates next to the struct, which newer compilers flag.
+#![allow(unused_parens)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
 
 use modular_bitfield::prelude::*;
 
+#[cfg(feature = "alloc")]
+mod apgcode;
+mod binary;
+mod bit;
+mod boundary;
+mod bounds;
+mod brain;
+mod builder;
+mod census;
+mod combine;
+#[cfg(feature = "alloc")]
+mod components;
+mod consistency;
+mod coords;
+mod cyclic;
+mod diff;
+mod display;
+#[cfg(feature = "alloc")]
+mod dynamic;
+#[cfg(feature = "c-ffi")]
+pub mod ffi;
+#[cfg(feature = "alloc")]
+mod fill;
+#[cfg(feature = "alloc")]
+mod format;
+mod generations;
+#[cfg(feature = "std")]
+mod hashlife;
+mod hex;
+#[cfg(feature = "alloc")]
+mod history;
+mod immigration;
+mod iter;
+#[macro_use]
+mod macros;
+mod neighborhood;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod pattern;
+mod random;
+#[cfg(feature = "alloc")]
+mod region;
+mod rule;
+#[cfg(feature = "alloc")]
+mod search;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod snapshot;
+#[cfg(feature = "std")]
+mod sparse;
+mod stats;
+mod step;
+mod symmetry;
+mod transform;
+#[cfg(feature = "alloc")]
+mod undo;
+mod universe3d;
+#[cfg(feature = "wasm")]
+mod wasm;
+mod wire;
+
+pub use binary::{universe_byte_size, BinaryDeserializeError};
+pub use bit::{bit_universe_words, BitUniverse};
+pub use boundary::BoundaryCondition;
+pub use bounds::BoundingBox;
+pub use brain::{BrainCell, BrainState, BrainUniverse};
+pub use builder::UniverseBuilder;
+pub use census::CensusReport;
+pub use consistency::ConsistencyError;
+#[cfg(feature = "alloc")]
+pub use coords::CoordError;
+pub use cyclic::{CyclicCell, CyclicUniverse};
+pub use diff::CellChange;
+#[cfg(feature = "alloc")]
+pub use dynamic::{DimError, DynamicUniverse, TileError};
+#[cfg(feature = "alloc")]
+pub use format::{
+    parse_life106, parse_plaintext, parse_rle, AsciiParseError, AsciiParseMode, Life106ParseError,
+    PlaintextParseError, RleParseError,
+};
+pub use generations::{GenerationsCell, GenerationsRuleSet, GenerationsUniverse};
+#[cfg(feature = "std")]
+pub use hashlife::HashlifeUniverse;
+pub use hex::HexUniverse;
+pub use immigration::{ColorCell, ColorState, ImmigrationUniverse};
+pub use iter::{AliveCellIter, CellIter, ChangedCellIter, StateChange};
+pub use neighborhood::{
+    ExtendedMooreNeighborhood, MooreNeighborhood, Neighborhood, RangeMooreNeighborhood,
+    VonNeumannNeighborhood,
+};
+#[cfg(feature = "alloc")]
+pub use pattern::PatternGrid;
+pub use pattern::PlacementError;
+pub use random::XorShift64;
+#[cfg(feature = "alloc")]
+pub use region::{CropError, EmbedError};
+pub use rule::{RuleParseError, RuleSet, RuleString};
+pub use snapshot::Snapshot;
+#[cfg(feature = "std")]
+pub use sparse::{CoordinateOutOfRange, SparseUniverse};
+pub use stats::{RegionError, UniverseStats};
+pub use step::{BatchEvolveIter, FixpointResult, StepResult};
+pub use symmetry::DihedralGroup;
+#[cfg(feature = "alloc")]
+pub use undo::UndoError;
+pub use universe3d::{Neighborhood3D, Rule3D, Universe3D};
+#[cfg(feature = "wasm")]
+pub use wasm::WasmUniverse;
+pub use wire::{WireCell, WireState, WireUniverse};
+
 /// The state of a Cell
 #[derive(Clone, Copy, Debug, PartialEq, Eq, BitfieldSpecifier)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[bits = 1]
 pub enum State {
     Dead,
@@ -11,22 +135,51 @@ pub enum State {
 }
 
 /// Cell
-#[bitfield(bits = 8)]
+///
+/// Besides its state, a cell caches its live neighbor count and its age: the
+/// number of consecutive generations it has been alive, saturating at 255.
+#[bitfield(bits = 16)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Cell {
     state: State,
     live_neighbors: B7,
+    generations_alive: B8,
 }
 
 impl Cell {
+    /// Creates an alive cell
+    pub fn new_alive() -> Self {
+        Self::new().with_state(State::Alive)
+    }
+
     /// Sets a new state for the cell based on the current state and live neighbors
-    fn evolve(&mut self) {
-        let state = match (self.state(), self.live_neighbors()) {
-            (State::Dead, 3) => State::Alive,
-            (State::Alive, 2) | (State::Alive, 3) => State::Alive,
-            _ => State::Dead,
+    ///
+    /// A newly born cell has age 1 and a surviving cell ages by one. A cell set
+    /// alive directly rather than born keeps age 0 until it first survives, when
+    /// it becomes 2.
+    fn evolve(&mut self, rule: &RuleSet) {
+        self.evolve_to(rule.next_state(self.state(), self.live_neighbors()));
+    }
+
+    /// Moves the cell to the given state, updating its age
+    fn evolve_to(&mut self, next: State) {
+        let age = match (self.state(), next) {
+            (State::Alive, State::Alive) => self.generations_alive().max(1).saturating_add(1),
+            (State::Dead, State::Alive) => 1,
+            (_, State::Dead) => 0,
         };
-        self.set_state(state);
+        self.set_state(next);
+        self.set_generations_alive(age);
+    }
+
+    /// Returns the number of consecutive generations the cell has been alive,
+    /// or 0 if it is dead
+    pub fn age(&self) -> u8 {
+        if self.is_alive() {
+            self.generations_alive()
+        } else {
+            0
+        }
     }
 
     /// Returns true if the cell is alive
@@ -41,71 +194,521 @@ impl Default for Cell {
     }
 }
 
+/// The error returned when a cell position lies outside the grid
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CellOutOfBounds {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for CellOutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cell ({}, {}) is out of bounds", self.row, self.column)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CellOutOfBounds {}
+
+/// Panics unless the cell lies inside a `W`x`H` grid
+pub(crate) fn check_bounds<const W: usize, const H: usize>(row: usize, column: usize) {
+    assert!(
+        row < H && column < W,
+        "cell ({}, {}) is out of bounds for a {}x{} universe",
+        row,
+        column,
+        W,
+        H
+    );
+}
+
 /// The Universe with a fixed width and height
-pub struct Universe<const W: usize, const H: usize> {
+///
+/// The universe is double-buffered: `evolve()` reads the current generation
+/// from `grid` and writes the next one into `grid_back`, then swaps the two.
+///
+/// `N` chooses which cells count as neighbors and defaults to the eight
+/// cells of the Moore neighborhood.
+pub struct Universe<const W: usize, const H: usize, N: Neighborhood = MooreNeighborhood> {
     grid: [[Cell; W]; H],
+    grid_back: [[Cell; W]; H],
     height: usize,
     width: usize,
+    rule: RuleSet,
+    boundary: BoundaryCondition,
+    generation: u64,
+    // Whether `grid` is exactly what the last evolution made of `grid_back`,
+    // which stops being true as soon as a cell is changed directly
+    evolved: bool,
+    #[cfg(feature = "alloc")]
+    history: Option<history::PopulationHistory>,
+    #[cfg(feature = "alloc")]
+    undo: Option<undo::UndoStack<W, H>>,
+    neighborhood: PhantomData<N>,
 }
 
-impl<const W: usize, const H: usize> Universe<W, H> {
-    pub fn new() -> Self {
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Creates an empty universe
+    ///
+    /// This is a `const fn`, so a universe can be placed in a `static`.
+    pub const fn new() -> Self {
+        Self::with_rule(RuleSet::CONWAY)
+    }
+
+    /// Creates an empty universe that evolves according to the given rule
+    pub const fn with_rule(rule: RuleSet) -> Self {
         Universe {
             width: W,
             height: H,
-            grid: [[Cell::default(); W]; H],
+            grid: [[Cell::new(); W]; H],
+            grid_back: [[Cell::new(); W]; H],
+            rule,
+            boundary: BoundaryCondition::Toroidal,
+            generation: 0,
+            evolved: false,
+            #[cfg(feature = "alloc")]
+            history: None,
+            #[cfg(feature = "alloc")]
+            undo: None,
+            neighborhood: PhantomData,
         }
     }
 
+    /// Creates an empty universe with the given boundary condition
+    pub fn with_boundary(boundary: BoundaryCondition) -> Self {
+        Universe {
+            boundary,
+            ..Self::new()
+        }
+    }
+
+    /// Chooses for each axis whether it wraps around, with dead cells beyond
+    /// the edges of an axis that does not
+    ///
+    /// This sets the boundary condition to [`BoundaryCondition::Cylindrical`].
+    pub fn set_wrapping(&mut self, wraps_rows: bool, wraps_cols: bool) {
+        self.boundary = BoundaryCondition::Cylindrical {
+            wrap_rows: wraps_rows,
+            wrap_cols: wraps_cols,
+        };
+    }
+
+    /// Returns the rule the universe evolves by
+    pub fn rule(&self) -> RuleSet {
+        self.rule
+    }
+
+    /// Returns how neighbors beyond the edges are treated
+    pub fn boundary(&self) -> BoundaryCondition {
+        self.boundary
+    }
+
+    /// Returns the number of times the universe has evolved
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Resets the generation counter to 0 without changing any cell
+    pub fn reset_generation(&mut self) {
+        self.generation = 0;
+    }
+
     /// Returns the reference to the grid
     pub fn grid(&self) -> [[Cell; W]; H] {
         self.grid
     }
 
+    /// Returns an iterator over `(row, column, cell)` in row-major order
+    pub fn cells(&self) -> CellIter<'_, W, H> {
+        CellIter::new(&self.grid)
+    }
+
+    /// Returns an iterator over the `(row, column)` positions of alive cells
+    pub fn alive_cells(&self) -> AliveCellIter<'_, W, H> {
+        AliveCellIter::new(&self.grid)
+    }
+
+    /// Calls `f(row, column, cell)` for every cell in row-major order
+    pub fn for_each<F: FnMut(usize, usize, &Cell)>(&self, mut f: F) {
+        for (row, cells) in self.grid.iter().enumerate() {
+            for (column, cell) in cells.iter().enumerate() {
+                f(row, column, cell);
+            }
+        }
+    }
+
+    /// Calls `f(row, column)` for every alive cell in row-major order
+    pub fn for_each_alive<F: FnMut(usize, usize)>(&self, mut f: F) {
+        self.for_each(|row, column, cell| {
+            if cell.is_alive() {
+                f(row, column);
+            }
+        });
+    }
+
+    /// Returns an iterator over the cells that were born or died in the last
+    /// `evolve()`
+    ///
+    /// Yields nothing until the universe has evolved, and again once any cell
+    /// is set directly, as the last generation no longer explains the grid.
+    pub fn changed_cells(&self) -> ChangedCellIter<'_, W, H> {
+        let previous = if self.evolved {
+            &self.grid_back
+        } else {
+            &self.grid
+        };
+        ChangedCellIter::new(&self.grid, previous)
+    }
+
+    /// Returns the number of `(births, deaths)` in the last `evolve()`, or
+    /// `(0, 0)` whenever [`changed_cells`](Self::changed_cells) yields nothing
+    pub fn count_changes(&self) -> (usize, usize) {
+        if !self.evolved {
+            return (0, 0);
+        }
+        let mut changes = (0, 0);
+        for (current, previous) in self
+            .grid
+            .iter()
+            .flatten()
+            .zip(self.grid_back.iter().flatten())
+        {
+            match (previous.is_alive(), current.is_alive()) {
+                (false, true) => changes.0 += 1,
+                (true, false) => changes.1 += 1,
+                _ => {}
+            }
+        }
+        changes
+    }
+
+    /// Returns how each cell changed in the last `evolve()`, or `None` for
+    /// cells that kept their state
+    ///
+    /// This is [`changed_cells`](Self::changed_cells) laid out as a grid, so
+    /// every entry is `None` whenever that iterator yields nothing.
+    pub fn delta_grid(&self) -> [[Option<StateChange>; W]; H] {
+        let mut delta = [[None; W]; H];
+        for (row, column, change) in self.changed_cells() {
+            delta[row][column] = Some(change);
+        }
+        delta
+    }
+
+    /// Returns the number of `(births, deaths)` in the last `evolve()`, the
+    /// same as [`count_changes`](Self::count_changes)
+    pub fn delta_count(&self) -> (usize, usize) {
+        self.count_changes()
+    }
+
+    /// Returns the live neighbor count of every cell in the current
+    /// generation, without advancing the simulation
+    pub fn neighbor_count_grid(&self) -> [[u8; W]; H] {
+        let mut counts = [[0; W]; H];
+        for (row, cells) in counts.iter_mut().enumerate() {
+            for (column, count) in cells.iter_mut().enumerate() {
+                *count = self.live_neighbor_count(row, column);
+            }
+        }
+        counts
+    }
+
+    /// Returns the sum of the kernel weights over each cell's surroundings,
+    /// with alive cells counted as 1 and dead cells as 0
+    ///
+    /// `kernel[i][j]` weights the cell `i - K / 2` rows and `j - K / 2`
+    /// columns away, so the kernel is centered for odd `K`. Cells off the grid
+    /// are resolved by the boundary condition like neighbors are. The Moore
+    /// neighbor count is the kernel `[[1, 1, 1], [1, 0, 1], [1, 1, 1]]`.
+    pub fn convolve<const K: usize>(&self, kernel: &[[i8; K]; K]) -> [[i32; W]; H] {
+        let center = (K / 2) as isize;
+        let mut sums = [[0; W]; H];
+        for (row, row_sums) in sums.iter_mut().enumerate() {
+            for (column, sum) in row_sums.iter_mut().enumerate() {
+                for (i, weights) in kernel.iter().enumerate() {
+                    let Some(source_row) = self.boundary.row_offset(row, i as isize - center, H)
+                    else {
+                        continue;
+                    };
+                    for (j, &weight) in weights.iter().enumerate() {
+                        if let Some(source_col) =
+                            self.boundary.col_offset(column, j as isize - center, W)
+                        {
+                            if self.grid[source_row][source_col].is_alive() {
+                                *sum += weight as i32;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        sums
+    }
+
+    /// Returns the number of alive cells without going through an iterator adapter
+    pub fn alive_cell_count(&self) -> usize {
+        let mut count = 0;
+        for row in self.grid.iter() {
+            for cell in row.iter() {
+                count += cell.is_alive() as usize;
+            }
+        }
+        count
+    }
+
     /// Sets the state of the cell
+    ///
+    /// Panics if the position is outside the grid.
     pub fn set_cell(&mut self, row: usize, column: usize, state: State) {
+        Self::check_bounds(row, column);
+        self.grid[row][column].set_state(state);
+        self.evolved = false;
+    }
+
+    /// Sets the state of each `(row, column, state)` entry in order, so a
+    /// later entry for the same cell wins
+    ///
+    /// Every position is checked before any cell changes: if one is outside
+    /// the grid, the first such position is returned and the universe is
+    /// left untouched.
+    pub fn set_cells(&mut self, cells: &[(usize, usize, State)]) -> Result<(), CellOutOfBounds> {
+        if let Some(&(row, column, _)) = cells
+            .iter()
+            .find(|&&(row, column, _)| row >= H || column >= W)
+        {
+            return Err(CellOutOfBounds { row, column });
+        }
+        for &(row, column, state) in cells {
+            self.grid[row][column].set_state(state);
+        }
+        self.evolved = false;
+        Ok(())
+    }
+
+    /// Sets each `(row, column)` alive, with the same all-or-nothing checking
+    /// as [`set_cells`](Self::set_cells)
+    pub fn set_cells_alive(&mut self, coords: &[(usize, usize)]) -> Result<(), CellOutOfBounds> {
+        if let Some(&(row, column)) = coords
+            .iter()
+            .find(|&&(row, column)| row >= H || column >= W)
+        {
+            return Err(CellOutOfBounds { row, column });
+        }
+        for &(row, column) in coords {
+            self.grid[row][column].set_state(State::Alive);
+        }
+        self.evolved = false;
+        Ok(())
+    }
+
+    /// Flips the state of the cell between alive and dead
+    ///
+    /// Panics if the position is outside the grid.
+    pub fn toggle_cell(&mut self, row: usize, column: usize) {
+        Self::check_bounds(row, column);
+        let state = match self.grid[row][column].state() {
+            State::Dead => State::Alive,
+            State::Alive => State::Dead,
+        };
         self.grid[row][column].set_state(state);
+        self.evolved = false;
+    }
+
+    /// Sets every cell dead
+    pub fn clear(&mut self) {
+        self.grid = [[Cell::default(); W]; H];
+        self.evolved = false;
+    }
+
+    /// Sets every cell to the given state
+    pub fn fill(&mut self, state: State) {
+        self.grid = [[Cell::new().with_state(state); W]; H];
+        self.evolved = false;
+    }
+
+    /// Returns true if the cell at the given position is alive
+    ///
+    /// Panics if the position is outside the grid.
+    pub fn is_alive(&self, row: usize, column: usize) -> bool {
+        Self::check_bounds(row, column);
+        self.grid[row][column].is_alive()
+    }
+
+    /// Returns the number of alive cells
+    pub fn count_alive(&self) -> usize {
+        self.alive_cell_count()
+    }
+
+    /// Returns the number of dead cells
+    pub fn count_dead(&self) -> usize {
+        W * H - self.count_alive()
+    }
+
+    /// Returns true if no cell is alive, stopping at the first alive cell
+    pub fn is_empty(&self) -> bool {
+        !self.grid.iter().flatten().any(|cell| cell.is_alive())
+    }
+
+    /// Returns true if every cell is alive, stopping at the first dead cell
+    pub fn is_full(&self) -> bool {
+        self.grid.iter().flatten().all(|cell| cell.is_alive())
     }
 
     /// Evolves the universe
+    ///
+    /// The next generation is computed into the back buffer from the current,
+    /// unmodified grid, so no cell ever observes a partially-updated neighbor.
     pub fn evolve(&mut self) {
+        let rule = self.rule;
+        self.apply_rule(|state, live_neighbors| rule.next_state(state, live_neighbors));
+    }
+
+    /// Evolves the universe with `rule(state, live_neighbors)` giving each
+    /// cell's next state instead of the universe's rule
+    ///
+    /// Cells are visited in row-major order, so a rule that keeps state, such
+    /// as a random number generator, sees them in a fixed sequence.
+    ///
+    /// Neighbors are counted and generations, ages and history are recorded
+    /// exactly as by `evolve()`.
+    #[inline]
+    pub fn apply_rule<F>(&mut self, mut rule: F)
+    where
+        F: FnMut(State, u8) -> State,
+    {
         for row in 0..self.height {
             for column in 0..self.width {
+                let mut cell = self.grid[row][column];
                 let live_neighbors = self.live_neighbor_count(row, column);
-                self.grid[row][column].set_live_neighbors(live_neighbors);
+                cell.set_live_neighbors(live_neighbors);
+                cell.evolve_to(rule(cell.state(), live_neighbors));
+                self.grid_back[row][column] = cell;
             }
         }
 
-        self.grid.iter_mut().for_each(|row| {
-            row.iter_mut().for_each(|cell| {
-                cell.evolve();
+        core::mem::swap(&mut self.grid, &mut self.grid_back);
+        self.generation += 1;
+        self.evolved = true;
+        #[cfg(feature = "alloc")]
+        self.record_history();
+    }
+
+    /// Evolves the universe with `rule(alive, neighbors)` giving whether each
+    /// cell is alive in the next generation
+    ///
+    /// `neighbors` holds the states of the eight Moore neighbors clockwise
+    /// from the top left: NW, N, NE, E, SE, S, SW, W, so the rule can depend
+    /// on where the alive neighbors are and not only on how many there are.
+    /// Neighbors outside a non-wrapping edge are dead. The universe's own
+    /// neighborhood is not used. Generations, ages and history are recorded
+    /// as by `evolve()`.
+    pub fn evolve_non_totalistic<F>(&mut self, rule: F)
+    where
+        F: Fn(bool, [bool; 8]) -> bool,
+    {
+        for row in 0..H {
+            for column in 0..W {
+                let mut cell = self.grid[row][column];
+                let neighbors = self.moore_neighbors(row, column);
+                cell.set_live_neighbors(neighbors.iter().filter(|&&alive| alive).count() as u8);
+                let next = if rule(cell.is_alive(), neighbors) {
+                    State::Alive
+                } else {
+                    State::Dead
+                };
+                cell.evolve_to(next);
+                self.grid_back[row][column] = cell;
+            }
+        }
+
+        core::mem::swap(&mut self.grid, &mut self.grid_back);
+        self.generation += 1;
+        self.evolved = true;
+        #[cfg(feature = "alloc")]
+        self.record_history();
+    }
+
+    /// Returns true if the next generation would be identical to the current one
+    ///
+    /// The universe is not modified. Returns as soon as a cell that would be
+    /// born or would die is found.
+    pub fn is_stable(&self) -> bool {
+        self.grid.iter().enumerate().all(|(row, cells)| {
+            cells.iter().enumerate().all(|(column, cell)| {
+                let state = cell.state();
+                self.rule
+                    .next_state(state, self.live_neighbor_count(row, column))
+                    == state
             })
-        });
+        })
+    }
+
+    /// Returns true if no cell would change in the next generation under the
+    /// universe's rule
+    ///
+    /// The same check as [`is_stable`](Self::is_stable), under the name used
+    /// for such patterns.
+    pub fn is_still_life(&self) -> bool {
+        self.is_stable()
+    }
+
+    fn check_bounds(row: usize, column: usize) {
+        check_bounds::<W, H>(row, column);
     }
 
     fn live_neighbor_count(&self, row: usize, column: usize) -> u8 {
-        [self.height - 1, 0, 1]
-            .iter()
-            .map(|&delta_row| {
-                [self.width - 1, 0, 1]
-                    .iter()
-                    .map(|&delta_col| {
-                        if delta_row == 0 && delta_col == 0 {
-                            0
-                        } else {
-                            // Calculate the neighbor's coordinates with wrapping
-                            let neighbor_row = (row + delta_row) % self.height;
-                            let neighbor_col = (column + delta_col) % self.width;
-                            self.grid[neighbor_row][neighbor_col].state() as u8
-                        }
-                    })
-                    .sum::<u8>()
-            })
-            .sum()
+        Self::neighbor_count_in(&self.grid, self.boundary, row, column)
+    }
+
+    /// Counts the live neighbors of a cell in `grid`
+    ///
+    /// Takes the grid explicitly so it can be read while the back buffer is
+    /// borrowed mutably.
+    fn neighbor_count_in(
+        grid: &[[Cell; W]; H],
+        boundary: BoundaryCondition,
+        row: usize,
+        column: usize,
+    ) -> u8 {
+        let mut count = 0;
+        N::for_each_offset(|delta_row, delta_col| {
+            // Resolve the neighbor's coordinates according to the boundary condition
+            let neighbor_row = boundary.row_offset(row, delta_row, H);
+            let neighbor_col = boundary.col_offset(column, delta_col, W);
+            if let (Some(neighbor_row), Some(neighbor_col)) = (neighbor_row, neighbor_col) {
+                count += grid[neighbor_row][neighbor_col].state() as u8;
+            }
+        });
+        count
+    }
+
+    /// Returns whether each Moore neighbor of a cell is alive, clockwise from
+    /// the top left
+    fn moore_neighbors(&self, row: usize, column: usize) -> [bool; 8] {
+        const OFFSETS: [(isize, isize); 8] = [
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+            (1, 0),
+            (1, -1),
+            (0, -1),
+        ];
+        OFFSETS.map(|(delta_row, delta_col)| {
+            match (
+                self.boundary.row_offset(row, delta_row, H),
+                self.boundary.col_offset(column, delta_col, W),
+            ) {
+                (Some(neighbor_row), Some(neighbor_col)) => {
+                    self.grid[neighbor_row][neighbor_col].is_alive()
+                }
+                _ => false,
+            }
+        })
     }
 
-    // For testing
-    #[allow(dead_code)]
     fn state_grid(&self) -> [[State; W]; H] {
         let mut states = [[State::Dead; W]; H];
         for (row_index, row) in self.grid.iter().enumerate() {
@@ -117,12 +720,139 @@ impl<const W: usize, const H: usize> Universe<W, H> {
     }
 }
 
-impl<const W: usize, const H: usize> Default for Universe<W, H> {
+// Implemented by hand so that cloning does not require `N: Clone`
+impl<const W: usize, const H: usize, N: Neighborhood> Clone for Universe<W, H, N> {
+    fn clone(&self) -> Self {
+        Universe {
+            grid: self.grid,
+            grid_back: self.grid_back,
+            height: self.height,
+            width: self.width,
+            rule: self.rule,
+            boundary: self.boundary,
+            generation: self.generation,
+            evolved: self.evolved,
+            #[cfg(feature = "alloc")]
+            history: self.history.clone(),
+            #[cfg(feature = "alloc")]
+            undo: self.undo.clone(),
+            neighborhood: PhantomData,
+        }
+    }
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Default for Universe<W, H, N> {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Universes are equal when every cell has the same state
+///
+/// The cached neighbor counts, the rule, the boundary and the generation are
+/// not compared.
+impl<const W: usize, const H: usize, N: Neighborhood> PartialEq for Universe<W, H, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.grid
+            .iter()
+            .flatten()
+            .zip(other.grid.iter().flatten())
+            .all(|(a, b)| a.state() == b.state())
+    }
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Eq for Universe<W, H, N> {}
+
+/// Creates a universe with `true` cells alive and `false` cells dead
+impl<const W: usize, const H: usize, N: Neighborhood> From<[[bool; W]; H]> for Universe<W, H, N> {
+    fn from(cells: [[bool; W]; H]) -> Self {
+        let mut universe = Universe::new();
+        for (row, states) in cells.iter().enumerate() {
+            for (column, &alive) in states.iter().enumerate() {
+                if alive {
+                    universe.grid[row][column].set_state(State::Alive);
+                }
+            }
+        }
+        universe
+    }
+}
+
+/// Returns the grid with `true` for alive cells and `false` for dead ones
+impl<const W: usize, const H: usize, N: Neighborhood> From<Universe<W, H, N>> for [[bool; W]; H] {
+    fn from(universe: Universe<W, H, N>) -> Self {
+        universe.grid.map(|row| row.map(|cell| cell.is_alive()))
+    }
+}
+
+/// Indexes the grid by `(row, column)`, panicking if the cell is out of bounds
+impl<const W: usize, const H: usize, N: Neighborhood> Index<(usize, usize)> for Universe<W, H, N> {
+    type Output = Cell;
+
+    fn index(&self, (row, column): (usize, usize)) -> &Cell {
+        Self::check_bounds(row, column);
+        &self.grid[row][column]
+    }
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> IndexMut<(usize, usize)>
+    for Universe<W, H, N>
+{
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Cell {
+        Self::check_bounds(row, column);
+        self.evolved = false;
+        &mut self.grid[row][column]
+    }
+}
+
+/// Operations shared by every universe, whether its dimensions are known at
+/// compile time or at runtime
+pub trait UniverseTrait {
+    /// Returns the number of columns
+    fn width(&self) -> usize;
+
+    /// Returns the number of rows
+    fn height(&self) -> usize;
+
+    /// Sets the state of the cell
+    fn set_cell(&mut self, row: usize, column: usize, state: State);
+
+    /// Returns true if the cell at the given position is alive
+    fn is_alive(&self, row: usize, column: usize) -> bool;
+
+    /// Evolves the universe by one generation
+    fn evolve(&mut self);
+
+    /// Returns the number of alive cells
+    fn count_alive(&self) -> usize;
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> UniverseTrait for Universe<W, H, N> {
+    fn width(&self) -> usize {
+        W
+    }
+
+    fn height(&self) -> usize {
+        H
+    }
+
+    fn set_cell(&mut self, row: usize, column: usize, state: State) {
+        Universe::set_cell(self, row, column, state)
+    }
+
+    fn is_alive(&self, row: usize, column: usize) -> bool {
+        Universe::is_alive(self, row, column)
+    }
+
+    fn evolve(&mut self) {
+        Universe::evolve(self)
+    }
+
+    fn count_alive(&self) -> usize {
+        Universe::count_alive(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -133,6 +863,7 @@ mod tests {
         assert_eq!(universe.width, 3);
         assert_eq!(universe.height, 3);
         assert_eq!(universe.grid, [[Cell::default(); 3]; 3]);
+        assert_eq!(universe.grid_back, [[Cell::default(); 3]; 3]);
     }
 
     #[test]
@@ -223,4 +954,594 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_evolve_keeps_previous_generation_in_back_buffer() {
+        let mut universe = Universe::<5, 5>::new();
+
+        // Horizontal blinker
+        universe.set_cell(2, 1, State::Alive);
+        universe.set_cell(2, 2, State::Alive);
+        universe.set_cell(2, 3, State::Alive);
+        let before = universe.state_grid();
+
+        universe.evolve();
+
+        let mut previous = [[State::Dead; 5]; 5];
+        for (row_index, row) in universe.grid_back.iter().enumerate() {
+            for (col_index, cell) in row.iter().enumerate() {
+                previous[row_index][col_index] = cell.state();
+            }
+        }
+        assert_eq!(previous, before);
+
+        // Vertical blinker
+        let mut expected = [[State::Dead; 5]; 5];
+        expected[1][2] = State::Alive;
+        expected[2][2] = State::Alive;
+        expected[3][2] = State::Alive;
+        assert_eq!(universe.state_grid(), expected);
+
+        universe.evolve();
+        assert_eq!(universe.state_grid(), before);
+    }
+
+    #[test]
+    fn test_population_predicates() {
+        let mut universe = Universe::<4, 4>::new();
+        assert!(universe.is_empty());
+        assert!(!universe.is_full());
+        assert_eq!(universe.count_alive(), 0);
+        assert_eq!(universe.count_dead(), 16);
+
+        // Seed the top half
+        for row in 0..2 {
+            for column in 0..4 {
+                universe.set_cell(row, column, State::Alive);
+            }
+        }
+        assert!(!universe.is_empty());
+        assert!(!universe.is_full());
+        assert_eq!(universe.count_alive(), 8);
+        assert_eq!(universe.count_alive() + universe.count_dead(), 16);
+
+        // Seed the bottom half
+        for row in 2..4 {
+            for column in 0..4 {
+                universe.set_cell(row, column, State::Alive);
+            }
+        }
+        assert!(!universe.is_empty());
+        assert!(universe.is_full());
+        assert_eq!(universe.count_dead(), 0);
+        assert_eq!(universe.count_alive() + universe.count_dead(), 16);
+    }
+
+    #[test]
+    fn test_clear_and_fill() {
+        let mut universe = Universe::<4, 3>::new();
+        universe.set_cell(1, 1, State::Alive);
+        universe.clear();
+        assert!(universe.is_empty());
+
+        universe.fill(State::Alive);
+        assert!(universe.is_full());
+
+        universe.fill(State::Dead);
+        assert!(universe.is_empty());
+
+        universe.fill(State::Alive);
+        universe.clear();
+        assert!(universe.is_empty());
+    }
+
+    #[test]
+    fn test_toggle_cell_round_trip() {
+        let mut universe = Universe::<3, 3>::new();
+        universe.toggle_cell(1, 2);
+        assert!(universe.is_alive(1, 2));
+        assert_eq!(universe.count_alive(), 1);
+        universe.toggle_cell(1, 2);
+        assert!(!universe.is_alive(1, 2));
+        assert!(universe.is_empty());
+    }
+
+    #[test]
+    fn test_toggle_cell_single_cell_grid() {
+        let mut universe = Universe::<1, 1>::new();
+        universe.toggle_cell(0, 0);
+        assert!(universe.is_full());
+        universe.toggle_cell(0, 0);
+        assert!(universe.is_empty());
+    }
+
+    #[test]
+    fn test_toggle_cell_twice_is_noop() {
+        let mut universe = Universe::<4, 4>::new();
+        universe.set_cell(0, 0, State::Alive);
+        universe.set_cell(3, 2, State::Alive);
+        let before = universe.state_grid();
+        for row in 0..4 {
+            for column in 0..4 {
+                universe.toggle_cell(row, column);
+                universe.toggle_cell(row, column);
+            }
+        }
+        assert_eq!(universe.state_grid(), before);
+    }
+
+    #[test]
+    #[should_panic(expected = "cell (3, 0) is out of bounds for a 2x3 universe")]
+    fn test_toggle_cell_out_of_bounds() {
+        let mut universe = Universe::<2, 3>::new();
+        universe.toggle_cell(3, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "cell (0, 2) is out of bounds for a 2x3 universe")]
+    fn test_set_cell_out_of_bounds() {
+        let mut universe = Universe::<2, 3>::new();
+        universe.set_cell(0, 2, State::Alive);
+    }
+
+    #[test]
+    fn test_is_stable() {
+        let mut universe = Universe::<5, 5>::new();
+        assert!(universe.is_stable());
+
+        // Single cell dies of loneliness
+        universe.set_cell(2, 2, State::Alive);
+        assert!(!universe.is_stable());
+
+        // Block
+        universe.set_cell(1, 1, State::Alive);
+        universe.set_cell(1, 2, State::Alive);
+        universe.set_cell(2, 1, State::Alive);
+        let before = universe.state_grid();
+        assert!(universe.is_stable());
+        assert_eq!(universe.state_grid(), before);
+
+        // Blinker
+        universe.clear();
+        universe.set_cell(2, 1, State::Alive);
+        universe.set_cell(2, 2, State::Alive);
+        universe.set_cell(2, 3, State::Alive);
+        assert!(!universe.is_stable());
+    }
+
+    #[test]
+    fn test_is_still_life() {
+        let mut universe = Universe::<6, 6>::new();
+        assert!(universe.is_still_life());
+
+        universe.set_cell(3, 3, State::Alive);
+        assert!(!universe.is_still_life());
+
+        universe.clear();
+        universe.seed_block(1, 1).unwrap();
+        let before = universe.clone();
+        for _ in 0..3 {
+            assert!(universe.is_still_life());
+        }
+        assert_eq!(universe, before);
+        assert_eq!(universe.generation(), 0);
+
+        universe.clear();
+        universe.seed_blinker(2, 1).unwrap();
+        assert!(!universe.is_still_life());
+    }
+
+    #[test]
+    fn test_is_still_life_agrees_with_is_stable() {
+        for seed in 0..20 {
+            let mut universe = Universe::<8, 8>::from_seed_deterministic(seed, 90);
+            for _ in 0..30 {
+                assert_eq!(universe.is_still_life(), universe.is_stable());
+                universe.evolve();
+            }
+        }
+    }
+
+    #[test]
+    fn test_generation_counter() {
+        let mut universe = Universe::<5, 5>::new();
+        assert_eq!(universe.generation(), 0);
+        assert_eq!(Universe::<5, 5>::default().generation(), 0);
+
+        universe.set_cell(2, 1, State::Alive);
+        universe.set_cell(2, 2, State::Alive);
+        universe.set_cell(2, 3, State::Alive);
+        for _ in 0..5 {
+            universe.evolve();
+        }
+        assert_eq!(universe.generation(), 5);
+        assert_eq!(universe.clone().generation(), 5);
+
+        let before = universe.state_grid();
+        universe.reset_generation();
+        assert_eq!(universe.generation(), 0);
+        assert_eq!(universe.state_grid(), before);
+
+        for _ in 0..10 {
+            universe.evolve();
+        }
+        assert_eq!(universe.generation(), 10);
+    }
+
+    #[test]
+    fn test_index() {
+        let mut universe = Universe::<4, 3>::new();
+        assert!(!universe[(1, 2)].is_alive());
+
+        universe[(1, 2)] = Cell::new_alive();
+        assert!(universe[(1, 2)].is_alive());
+        assert!(universe.is_alive(1, 2));
+        assert_eq!(universe.count_alive(), 1);
+
+        universe.set_cell(2, 3, State::Alive);
+        assert_eq!(universe[(2, 3)], Cell::new_alive());
+        universe[(2, 3)] = Cell::new();
+        assert!(!universe.is_alive(2, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_index_out_of_bounds() {
+        let universe = Universe::<4, 3>::new();
+        let _ = universe[(0, 4)];
+    }
+
+    #[test]
+    #[should_panic(expected = "cell (3, 0) is out of bounds for a 4x3 universe")]
+    fn test_index_mut_out_of_bounds() {
+        let mut universe = Universe::<4, 3>::new();
+        universe[(3, 0)] = Cell::new_alive();
+    }
+
+    #[test]
+    fn test_from_bool_array() {
+        let universe: Universe<3, 3> = [
+            [true, false, true],
+            [false, true, false],
+            [true, false, true],
+        ]
+        .into();
+        assert_eq!(universe.count_alive(), 5);
+        assert!(universe.is_alive(0, 0) && universe.is_alive(1, 1) && universe.is_alive(2, 2));
+        assert!(!universe.is_alive(0, 1));
+    }
+
+    #[test]
+    fn test_bool_array_round_trip() {
+        let mut glider = Universe::<4, 4>::new();
+        glider.seed_glider(0, 0).unwrap();
+        let expected = glider.state_grid();
+
+        let cells: [[bool; 4]; 4] = glider.into();
+        assert_eq!(
+            cells,
+            [
+                [false, true, false, false],
+                [false, false, true, false],
+                [true, true, true, false],
+                [false, false, false, false],
+            ]
+        );
+        assert_eq!(Universe::<4, 4>::from(cells).state_grid(), expected);
+    }
+
+    #[test]
+    fn test_eq_compares_states() {
+        assert_eq!(Universe::<4, 4>::new(), Universe::<4, 4>::new());
+
+        let mut a = Universe::<4, 4>::new();
+        a.set_cell(1, 1, State::Alive);
+        a.set_cell(2, 2, State::Alive);
+        let mut b = Universe::<4, 4>::new();
+        b.set_cell(2, 2, State::Alive);
+        b.set_cell(0, 0, State::Alive);
+        b.set_cell(1, 1, State::Alive);
+        b.set_cell(0, 0, State::Dead);
+        assert_eq!(a, b);
+        assert_eq!(a, a.clone());
+
+        b.toggle_cell(3, 3);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_eq_ignores_cached_neighbors() {
+        let blinker = [
+            [false; 5],
+            [false; 5],
+            [false, true, true, true, false],
+            [false; 5],
+            [false; 5],
+        ];
+        let mut evolved = Universe::<5, 5>::from(blinker);
+        evolved.evolve();
+        evolved.evolve();
+        let fresh = Universe::<5, 5>::from(blinker);
+        assert_ne!(
+            evolved.grid[2][2].live_neighbors(),
+            fresh.grid[2][2].live_neighbors()
+        );
+        assert_eq!(evolved, fresh);
+    }
+
+    static EMPTY: Universe<5, 5> = Universe::new();
+    const SMALL: Universe<3, 3> = Universe::new();
+
+    #[test]
+    fn test_const_new() {
+        assert!(EMPTY.is_empty());
+        assert_eq!(EMPTY.generation(), 0);
+        let mut small = SMALL;
+        assert!(small.is_empty());
+        small.set_cell(1, 1, State::Alive);
+        assert_eq!(small.count_alive(), 1);
+    }
+
+    #[test]
+    fn test_set_cells_last_entry_wins() {
+        let mut universe = Universe::<4, 4>::new();
+        universe
+            .set_cells(&[
+                (1, 1, State::Alive),
+                (2, 2, State::Alive),
+                (1, 1, State::Dead),
+            ])
+            .unwrap();
+        assert!(!universe.is_alive(1, 1));
+        assert!(universe.is_alive(2, 2));
+    }
+
+    #[test]
+    fn test_set_cells_out_of_bounds_is_atomic() {
+        let mut universe = Universe::<4, 4>::new();
+        let result = universe.set_cells(&[
+            (0, 0, State::Alive),
+            (4, 1, State::Alive),
+            (1, 9, State::Alive),
+        ]);
+        assert_eq!(result, Err(CellOutOfBounds { row: 4, column: 1 }));
+        assert!(universe.is_empty());
+        assert_eq!(
+            universe.set_cells_alive(&[(0, 0), (3, 4)]),
+            Err(CellOutOfBounds { row: 3, column: 4 })
+        );
+        assert!(universe.is_empty());
+    }
+
+    #[test]
+    fn test_set_cells_alive_glider() {
+        let mut universe = Universe::<5, 5>::new();
+        universe
+            .set_cells_alive(&[(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)])
+            .unwrap();
+        let mut expected = Universe::<5, 5>::new();
+        expected.seed_glider(0, 0).unwrap();
+        assert_eq!(universe, expected);
+    }
+
+    #[test]
+    fn test_born_cell_has_age_one() {
+        let mut blinker = Universe::<5, 5>::new();
+        blinker.set_cells_alive(&[(2, 1), (2, 2), (2, 3)]).unwrap();
+        blinker.evolve();
+        let grid = blinker.grid();
+        assert_eq!(grid[1][2].age(), 1);
+        assert_eq!(grid[3][2].age(), 1);
+        assert_eq!(grid[2][2].age(), 2);
+        assert_eq!(grid[2][1].age(), 0);
+
+        blinker.evolve();
+        let grid = blinker.grid();
+        assert_eq!(grid[2][2].age(), 3);
+        assert_eq!(grid[2][1].age(), 1);
+        assert_eq!(grid[1][2].age(), 0);
+    }
+
+    #[test]
+    fn test_block_cells_age() {
+        let mut block = Universe::<4, 4>::new();
+        block.seed_block(1, 1).unwrap();
+        for generation in 1..=5u8 {
+            block.evolve();
+            for cell in block.grid().iter().flatten().filter(|cell| cell.is_alive()) {
+                assert_eq!(cell.age(), generation + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_age_saturates() {
+        let mut block = Universe::<4, 4>::new();
+        block.seed_block(1, 1).unwrap();
+        for _ in 0..300 {
+            block.evolve();
+        }
+        assert_eq!(block.grid()[1][1].age(), 255);
+        block.set_cell(1, 1, State::Dead);
+        assert_eq!(block.grid()[1][1].age(), 0);
+    }
+
+    #[test]
+    fn test_apply_rule_matches_evolve() {
+        let mut evolved = Universe::<8, 8>::new();
+        evolved.seed_glider(1, 1).unwrap();
+        evolved.seed_blinker(6, 2).unwrap();
+        let mut applied = evolved.clone();
+        for _ in 0..12 {
+            evolved.evolve();
+            applied.apply_rule(|state, live_neighbors| match (state, live_neighbors) {
+                (State::Alive, 2 | 3) | (State::Dead, 3) => State::Alive,
+                _ => State::Dead,
+            });
+            assert_eq!(applied, evolved);
+        }
+        assert_eq!(applied.generation(), 12);
+    }
+
+    #[test]
+    fn test_non_totalistic_conway_matches_evolve() {
+        let mut evolved = Universe::<8, 8>::new();
+        evolved.seed_glider(1, 1).unwrap();
+        evolved.seed_blinker(6, 2).unwrap();
+        let mut applied = evolved.clone();
+        for _ in 0..12 {
+            evolved.evolve();
+            applied.evolve_non_totalistic(|alive, neighbors| {
+                matches!(
+                    (alive, neighbors.iter().filter(|&&n| n).count()),
+                    (true, 2 | 3) | (false, 3)
+                )
+            });
+            assert_eq!(applied, evolved);
+        }
+        assert_eq!(applied.generation(), 12);
+    }
+
+    #[test]
+    fn test_non_totalistic_neighbor_order() {
+        // Birth only between an alive NE and an alive SW neighbor
+        let diagonal = |alive: bool, neighbors: [bool; 8]| {
+            alive || neighbors == [false, false, true, false, false, false, true, false]
+        };
+        let mut universe = Universe::<6, 6>::with_boundary(BoundaryCondition::FixedDead);
+        universe.set_cells_alive(&[(1, 3), (3, 1)]).unwrap();
+        universe.evolve_non_totalistic(diagonal);
+        assert_eq!(universe.count_alive(), 3);
+        assert!(universe.is_alive(2, 2));
+
+        // The mirror image has NW and SE neighbors instead
+        let mut universe = Universe::<6, 6>::with_boundary(BoundaryCondition::FixedDead);
+        universe.set_cells_alive(&[(1, 1), (3, 3)]).unwrap();
+        universe.evolve_non_totalistic(diagonal);
+        assert_eq!(universe.count_alive(), 2);
+
+        universe.evolve_non_totalistic(|_, _| false);
+        assert!(universe.is_empty());
+    }
+
+    #[test]
+    fn test_apply_constant_rules() {
+        let mut universe = Universe::<6, 6>::new();
+        universe.seed_glider(0, 0).unwrap();
+        universe.apply_rule(|_, _| State::Dead);
+        assert!(universe.is_empty());
+
+        // Every cell is alive afterwards, whatever its state or neighbors
+        universe.set_cell(3, 3, State::Alive);
+        universe.apply_rule(|_, _| State::Alive);
+        assert!(universe.is_full());
+        universe.apply_rule(|_, _| State::Alive);
+        assert!(universe.is_full());
+    }
+
+    #[test]
+    fn test_for_each_alive_fills_buffer() {
+        let mut universe = Universe::<6, 6>::new();
+        universe.seed_glider(1, 1).unwrap();
+        let mut buf = [(0, 0); 10];
+        let mut len = 0;
+        universe.for_each_alive(|row, column| {
+            buf[len] = (row, column);
+            len += 1;
+        });
+        assert_eq!(len, universe.count_alive());
+        assert_eq!(&buf[..len], &[(1, 2), (2, 3), (3, 1), (3, 2), (3, 3)]);
+    }
+
+    #[test]
+    fn test_for_each_visits_every_cell() {
+        let mut calls = 0;
+        let mut alive = 0;
+        let mut universe = Universe::<7, 3>::new();
+        universe.seed_blinker(1, 2).unwrap();
+        universe.for_each(|_, _, cell| {
+            calls += 1;
+            alive += cell.is_alive() as usize;
+        });
+        assert_eq!(calls, 7 * 3);
+        assert_eq!(alive, 3);
+
+        Universe::<4, 4>::new().for_each_alive(|_, _| panic!("no cell is alive"));
+    }
+
+    #[test]
+    fn test_neighbor_count_grid() {
+        assert_eq!(Universe::<5, 5>::new().neighbor_count_grid(), [[0; 5]; 5]);
+
+        let mut universe = Universe::<5, 5>::with_boundary(BoundaryCondition::FixedDead);
+        universe.seed_block(1, 1).unwrap();
+        let counts = universe.neighbor_count_grid();
+        for (row, column) in [(1, 1), (1, 2), (2, 1), (2, 2)] {
+            assert_eq!(counts[row][column], 3);
+        }
+        for (row, row_counts) in counts.iter().enumerate() {
+            for (column, &count) in row_counts.iter().enumerate() {
+                assert_eq!(count, universe.live_neighbor_count(row, column));
+            }
+        }
+        assert_eq!(universe.generation(), 0);
+    }
+
+    #[test]
+    fn test_convolve_moore_kernel_counts_neighbors() {
+        const MOORE: [[i8; 3]; 3] = [[1, 1, 1], [1, 0, 1], [1, 1, 1]];
+        for boundary in [BoundaryCondition::Toroidal, BoundaryCondition::FixedDead] {
+            let mut universe = Universe::<6, 6>::from_seed_deterministic(99, 100);
+            universe.boundary = boundary;
+            let sums = universe.convolve(&MOORE);
+            let counts = universe.neighbor_count_grid();
+            for (sums, counts) in sums.iter().zip(counts.iter()) {
+                for (&sum, &count) in sums.iter().zip(counts.iter()) {
+                    assert_eq!(sum, count as i32);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_convolve_identity_and_negation() {
+        let mut universe = Universe::<6, 6>::new();
+        universe.seed_glider(0, 0).unwrap();
+        let identity = universe.convolve(&[[0, 0, 0], [0, 1, 0], [0, 0, 0]]);
+        let negated = universe.convolve(&[[0, 0, 0], [0, -1, 0], [0, 0, 0]]);
+        for (row, (identity, negated)) in identity.iter().zip(negated.iter()).enumerate() {
+            for column in 0..6 {
+                let alive = universe.is_alive(row, column) as i32;
+                assert_eq!(identity[column], alive);
+                assert_eq!(negated[column], -alive);
+            }
+        }
+        assert_eq!(universe.convolve(&[[1]]), identity);
+    }
+
+    #[test]
+    fn test_convolve_is_linear() {
+        let universe = Universe::<8, 5>::from_seed_deterministic(7, 90);
+        let a = [[1, -2, 0, 3], [0, 4, 1, 0], [-1, 0, 0, 2], [5, 0, -3, 1]];
+        let b = [[0, 1, 1, -1], [2, 0, 0, 3], [1, 1, -4, 0], [0, -2, 6, 0]];
+        let mut sum = [[0; 4]; 4];
+        for i in 0..4 {
+            for j in 0..4 {
+                sum[i][j] = a[i][j] + b[i][j];
+            }
+        }
+        let (conv_a, conv_b, conv_sum) = (
+            universe.convolve(&a),
+            universe.convolve(&b),
+            universe.convolve(&sum),
+        );
+        for row in 0..5 {
+            for column in 0..8 {
+                assert_eq!(
+                    conv_sum[row][column],
+                    conv_a[row][column] + conv_b[row][column]
+                );
+            }
+        }
+    }
 }
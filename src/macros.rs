@@ -0,0 +1,76 @@
+/// Builds a [`Universe`](crate::Universe) from a grid literal
+///
+/// Cells are `.` for dead and `#` or `*` for alive, separated by whitespace,
+/// and rows are separated by `/`. The width and the height are taken from the
+/// literal, and rows of different lengths fail to compile.
+///
+/// ```
+/// use game_of_life_core::universe;
+///
+/// let glider = universe! { . # . / . . # / # # # };
+/// assert_eq!(glider.count_alive(), 5);
+/// assert!(glider.is_alive(2, 0));
+/// ```
+///
+/// ```compile_fail
+/// use game_of_life_core::universe;
+///
+/// let ragged = universe! { . # / . . # };
+/// ```
+///
+/// Every cell takes one level of macro recursion, so literals of more than
+/// about a hundred cells need a higher `#![recursion_limit]`.
+#[macro_export]
+macro_rules! universe {
+    (@cell .) => {
+        false
+    };
+    (@cell #) => {
+        true
+    };
+    (@cell *) => {
+        true
+    };
+    (@rows [$($rows:tt)*] [$($row:tt)*] / $($rest:tt)*) => {
+        $crate::universe!(@rows [$($rows)* [$($row)*]] [] $($rest)*)
+    };
+    (@rows [$($rows:tt)*] [$($row:tt)*] $cell:tt $($rest:tt)*) => {
+        $crate::universe!(@rows [$($rows)*] [$($row)* $cell] $($rest)*)
+    };
+    (@rows [$($rows:tt)*] [$($row:tt)*]) => {
+        <$crate::Universe<_, _>>::from([
+            $($crate::universe!(@row $rows),)*
+            $crate::universe!(@row [$($row)*]),
+        ])
+    };
+    (@row [$($cell:tt)*]) => {
+        [$($crate::universe!(@cell $cell)),*]
+    };
+    ($($tokens:tt)+) => {
+        $crate::universe!(@rows [] [] $($tokens)+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Universe;
+
+    #[test]
+    fn test_glider_literal() {
+        let glider = universe! { . # . / . . # / # # # };
+        let mut expected = Universe::<3, 3>::new();
+        expected.seed_glider(0, 0).unwrap();
+        assert_eq!(glider, expected);
+        assert_eq!(glider.count_alive(), 5);
+    }
+
+    #[test]
+    fn test_dimensions_from_literal() {
+        let universe: Universe<4, 2> = universe! { * . . # / . . . . };
+        assert!(universe.is_alive(0, 0) && universe.is_alive(0, 3));
+        assert_eq!(universe.count_alive(), 2);
+
+        let single = universe! { # };
+        assert_eq!(single.count_alive(), 1);
+    }
+}
@@ -0,0 +1,164 @@
+/// Which cells around a cell count as its neighbors
+///
+/// The neighborhood is a type parameter of `Universe`, so choosing one costs
+/// nothing at runtime. Implementers list the `(row, column)` offsets of the
+/// neighbors, and the universe resolves them against its boundary condition.
+pub trait Neighborhood {
+    /// Calls `f(delta_row, delta_col)` for the offset of every neighbor
+    fn for_each_offset<F: FnMut(isize, isize)>(f: F);
+}
+
+/// The eight cells surrounding a cell, including the diagonals
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MooreNeighborhood;
+
+impl Neighborhood for MooreNeighborhood {
+    fn for_each_offset<F: FnMut(isize, isize)>(mut f: F) {
+        for delta_row in -1..=1 {
+            for delta_col in -1..=1 {
+                if delta_row != 0 || delta_col != 0 {
+                    f(delta_row, delta_col);
+                }
+            }
+        }
+    }
+}
+
+/// The four cells sharing an edge with a cell: north, south, east and west
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct VonNeumannNeighborhood;
+
+impl Neighborhood for VonNeumannNeighborhood {
+    fn for_each_offset<F: FnMut(isize, isize)>(mut f: F) {
+        for (delta_row, delta_col) in [(-1, 0), (1, 0), (0, 1), (0, -1)] {
+            f(delta_row, delta_col);
+        }
+    }
+}
+
+/// Every cell within `R` rows and columns of a cell, `(2R + 1)^2 - 1` in all
+///
+/// A cell caches up to 127 live neighbors, so `R` may be at most 5. Note that
+/// `RuleSet` only has entries for 0 to 8 neighbors, and treats higher counts
+/// as death in both tables.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RangeMooreNeighborhood<const R: u8>;
+
+/// The 24 cells of the range-2 Moore neighborhood
+pub type ExtendedMooreNeighborhood = RangeMooreNeighborhood<2>;
+
+impl<const R: u8> RangeMooreNeighborhood<R> {
+    const CHECK_RANGE: () = assert!(R <= 5, "RangeMooreNeighborhood range must be at most 5");
+}
+
+impl<const R: u8> Neighborhood for RangeMooreNeighborhood<R> {
+    fn for_each_offset<F: FnMut(isize, isize)>(mut f: F) {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CHECK_RANGE;
+        let range = R as isize;
+        for delta_row in -range..=range {
+            for delta_col in -range..=range {
+                if delta_row != 0 || delta_col != 0 {
+                    f(delta_row, delta_col);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{State, Universe};
+
+    fn count_offsets<N: Neighborhood>() -> usize {
+        let mut count = 0;
+        N::for_each_offset(|_, _| count += 1);
+        count
+    }
+
+    #[test]
+    fn test_offset_counts() {
+        assert_eq!(count_offsets::<MooreNeighborhood>(), 8);
+        assert_eq!(count_offsets::<VonNeumannNeighborhood>(), 4);
+        assert_eq!(count_offsets::<RangeMooreNeighborhood<1>>(), 8);
+        assert_eq!(count_offsets::<ExtendedMooreNeighborhood>(), 24);
+        assert_eq!(count_offsets::<RangeMooreNeighborhood<3>>(), 48);
+        assert_eq!(count_offsets::<RangeMooreNeighborhood<5>>(), 120);
+    }
+
+    #[test]
+    fn test_extended_moore_full_grid() {
+        let mut universe = Universe::<7, 7, ExtendedMooreNeighborhood>::new();
+        universe.fill(State::Alive);
+        assert_eq!(universe.live_neighbor_count(3, 3), 24);
+
+        let mut universe = Universe::<7, 7, RangeMooreNeighborhood<3>>::new();
+        universe.fill(State::Alive);
+        assert_eq!(universe.live_neighbor_count(3, 3), 48);
+    }
+
+    #[test]
+    fn test_extended_moore_reaches_two_cells() {
+        let mut universe = Universe::<9, 9, ExtendedMooreNeighborhood>::new();
+        universe.set_cell(2, 2, State::Alive);
+        universe.set_cell(6, 6, State::Alive);
+        universe.set_cell(4, 7, State::Alive);
+        assert_eq!(universe.live_neighbor_count(4, 4), 2);
+        assert_eq!(universe.live_neighbor_count(4, 5), 2);
+    }
+
+    #[test]
+    fn test_von_neumann_ignores_diagonals() {
+        let mut universe = Universe::<5, 5, VonNeumannNeighborhood>::new();
+        for row in 1..4 {
+            for column in 1..4 {
+                universe.set_cell(row, column, State::Alive);
+            }
+        }
+        assert_eq!(universe.live_neighbor_count(2, 2), 4);
+        assert_eq!(universe.live_neighbor_count(1, 1), 2);
+
+        let mut moore = Universe::<5, 5>::new();
+        moore.set_cell(1, 1, State::Alive);
+        assert_eq!(moore.live_neighbor_count(2, 2), 1);
+        let mut von_neumann = Universe::<5, 5, VonNeumannNeighborhood>::new();
+        von_neumann.set_cell(1, 1, State::Alive);
+        assert_eq!(von_neumann.live_neighbor_count(2, 2), 0);
+    }
+
+    #[test]
+    fn test_von_neumann_wraps() {
+        let mut universe = Universe::<5, 5, VonNeumannNeighborhood>::new();
+        universe.set_cell(4, 0, State::Alive);
+        universe.set_cell(0, 4, State::Alive);
+        universe.set_cell(4, 4, State::Alive);
+        assert_eq!(universe.live_neighbor_count(0, 0), 2);
+    }
+
+    #[test]
+    fn test_no_glider_under_von_neumann() {
+        let mut universe = Universe::<8, 8, VonNeumannNeighborhood>::new();
+        universe.seed_glider(2, 2).unwrap();
+        let mut expected = Universe::<8, 8, VonNeumannNeighborhood>::new();
+        expected.seed_glider(3, 3).unwrap();
+
+        for _ in 0..4 {
+            universe.evolve();
+        }
+        assert_ne!(universe, expected);
+        assert_ne!(universe.count_alive(), 5);
+    }
+
+    #[test]
+    fn test_default_neighborhood_is_moore() {
+        let mut universe: Universe<8, 8> = Universe::new();
+        universe.seed_glider(2, 2).unwrap();
+        let mut expected = Universe::<8, 8, MooreNeighborhood>::new();
+        expected.seed_glider(3, 3).unwrap();
+        for _ in 0..4 {
+            universe.evolve();
+        }
+        assert_eq!(universe, expected);
+    }
+}
@@ -0,0 +1,102 @@
+use rayon::prelude::*;
+
+use crate::{Neighborhood, Universe};
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Evolves the universe, computing rows in parallel on the rayon thread pool
+    ///
+    /// Produces exactly the same generation as `evolve()`. Each row of the
+    /// back buffer is written by one task, which only reads the current grid.
+    ///
+    /// Scheduling the row tasks has a fixed cost per step, so for grids below
+    /// roughly 128x128 cells `evolve()` is usually faster. The
+    /// `parallel_benchmark` example measures the speedup on a given machine.
+    pub fn evolve_parallel(&mut self) {
+        let grid = &self.grid;
+        let rule = &self.rule;
+        let boundary = self.boundary;
+        self.grid_back
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(row, back_row)| {
+                for (column, back_cell) in back_row.iter_mut().enumerate() {
+                    let mut cell = grid[row][column];
+                    cell.set_live_neighbors(Self::neighbor_count_in(grid, boundary, row, column));
+                    cell.evolve(rule);
+                    *back_cell = cell;
+                }
+            });
+
+        core::mem::swap(&mut self.grid, &mut self.grid_back);
+        self.generation += 1;
+        self.evolved = true;
+        self.record_history();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{Rng, SeedableRng};
+
+    use crate::{BoundaryCondition, RuleSet, State, Universe};
+
+    fn random_universe<const W: usize, const H: usize>(seed: u64) -> Universe<W, H> {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let mut universe = Universe::new();
+        for row in 0..H {
+            for column in 0..W {
+                if rng.gen_bool(0.5) {
+                    universe.set_cell(row, column, State::Alive);
+                }
+            }
+        }
+        universe
+    }
+
+    #[test]
+    fn test_matches_evolve() {
+        for boundary in [
+            BoundaryCondition::Toroidal,
+            BoundaryCondition::FixedDead,
+            BoundaryCondition::Reflective,
+        ] {
+            let mut sequential = random_universe::<37, 23>(30);
+            sequential.boundary = boundary;
+            let mut parallel = sequential.clone();
+            for _ in 0..20 {
+                sequential.evolve();
+                parallel.evolve_parallel();
+                assert_eq!(parallel.state_grid(), sequential.state_grid());
+                assert_eq!(parallel.grid_back, sequential.grid_back);
+            }
+            assert_eq!(parallel.generation(), 20);
+        }
+    }
+
+    #[test]
+    fn test_matches_evolve_with_rule() {
+        let mut sequential = random_universe::<16, 16>(31);
+        sequential.rule = RuleSet::HIGH_LIFE;
+        let mut parallel = sequential.clone();
+        for _ in 0..10 {
+            sequential.evolve();
+            parallel.evolve_parallel();
+        }
+        assert_eq!(parallel.state_grid(), sequential.state_grid());
+    }
+
+    #[test]
+    fn test_glider_and_history() {
+        let mut universe = Universe::<8, 8>::new();
+        universe.seed_glider(0, 0).unwrap();
+        universe.enable_history(4);
+        for _ in 0..32 {
+            universe.evolve_parallel();
+        }
+        // A glider returns to its starting position on an 8x8 torus after 32 steps
+        let mut expected = Universe::<8, 8>::new();
+        expected.seed_glider(0, 0).unwrap();
+        assert_eq!(universe.state_grid(), expected.state_grid());
+        assert!(universe.history().unwrap().iter().all(|&count| count == 5));
+    }
+}
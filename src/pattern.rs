@@ -0,0 +1,363 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+use core::fmt;
+
+use crate::{Neighborhood, State, Universe};
+
+/// A rectangular pattern read from a pattern file
+///
+/// `cells[row][column]` is true for alive cells. Every row holds exactly
+/// `width` cells.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PatternGrid {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<Vec<bool>>,
+    pub rule: Option<String>,
+    pub name: Option<String>,
+}
+
+#[cfg(feature = "alloc")]
+impl PatternGrid {
+    /// Creates an all-dead pattern of the given size
+    pub fn new(width: usize, height: usize) -> Self {
+        PatternGrid {
+            width,
+            height,
+            cells: vec![vec![false; width]; height],
+            rule: None,
+            name: None,
+        }
+    }
+
+    /// Returns the number of alive cells
+    pub fn count_alive(&self) -> usize {
+        self.cells.iter().flatten().filter(|&&alive| alive).count()
+    }
+}
+
+/// Errors returned when a pattern cannot be placed in a universe
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PlacementError {
+    /// The pattern would extend past the edge of the grid
+    OutOfBounds,
+    /// The pattern is larger than the grid wherever it is placed
+    UniverseTooSmall {
+        required_rows: usize,
+        required_cols: usize,
+        available_rows: usize,
+        available_cols: usize,
+    },
+}
+
+impl fmt::Display for PlacementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlacementError::OutOfBounds => write!(f, "pattern extends past the edge of the grid"),
+            PlacementError::UniverseTooSmall {
+                required_rows,
+                required_cols,
+                available_rows,
+                available_cols,
+            } => write!(
+                f,
+                "pattern needs {} rows and {} columns but the grid has {} rows and {} columns",
+                required_rows, required_cols, available_rows, available_cols
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PlacementError {}
+
+/// Alive cells of each preset as `(row, column)` offsets from its top-left corner
+const GLIDER: &[(usize, usize)] = &[(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)];
+const BLINKER: &[(usize, usize)] = &[(0, 0), (0, 1), (0, 2)];
+const BLOCK: &[(usize, usize)] = &[(0, 0), (0, 1), (1, 0), (1, 1)];
+const TOAD: &[(usize, usize)] = &[(0, 1), (0, 2), (0, 3), (1, 0), (1, 1), (1, 2)];
+const BEACON: &[(usize, usize)] = &[(0, 0), (0, 1), (1, 0), (2, 3), (3, 2), (3, 3)];
+const GOSPER_GLIDER_GUN: [(usize, usize); 36] = [
+    (0, 24),
+    (1, 22),
+    (1, 24),
+    (2, 12),
+    (2, 13),
+    (2, 20),
+    (2, 21),
+    (2, 34),
+    (2, 35),
+    (3, 11),
+    (3, 15),
+    (3, 20),
+    (3, 21),
+    (3, 34),
+    (3, 35),
+    (4, 0),
+    (4, 1),
+    (4, 10),
+    (4, 16),
+    (4, 20),
+    (4, 21),
+    (5, 0),
+    (5, 1),
+    (5, 10),
+    (5, 14),
+    (5, 16),
+    (5, 17),
+    (5, 22),
+    (5, 24),
+    (6, 10),
+    (6, 16),
+    (6, 24),
+    (7, 11),
+    (7, 15),
+    (8, 12),
+    (8, 13),
+];
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Seeds a glider heading down and to the right
+    pub fn seed_glider(&mut self, top_row: usize, left_col: usize) -> Result<(), PlacementError> {
+        self.seed_preset(GLIDER, top_row, left_col)
+    }
+
+    /// Seeds a horizontal blinker, a period 2 oscillator
+    pub fn seed_blinker(&mut self, top_row: usize, left_col: usize) -> Result<(), PlacementError> {
+        self.seed_preset(BLINKER, top_row, left_col)
+    }
+
+    /// Seeds a 2x2 block, a still life
+    pub fn seed_block(&mut self, top_row: usize, left_col: usize) -> Result<(), PlacementError> {
+        self.seed_preset(BLOCK, top_row, left_col)
+    }
+
+    /// Seeds a toad, a period 2 oscillator
+    pub fn seed_toad(&mut self, top_row: usize, left_col: usize) -> Result<(), PlacementError> {
+        self.seed_preset(TOAD, top_row, left_col)
+    }
+
+    /// Seeds a beacon, a period 2 oscillator
+    pub fn seed_beacon(&mut self, top_row: usize, left_col: usize) -> Result<(), PlacementError> {
+        self.seed_preset(BEACON, top_row, left_col)
+    }
+
+    /// Seeds a Gosper glider gun, which emits a glider every 30 generations
+    ///
+    /// The gun occupies 9 rows and 36 columns and fires down and to the right.
+    pub fn seed_gosper_glider_gun(
+        &mut self,
+        top_row: usize,
+        left_col: usize,
+    ) -> Result<(), PlacementError> {
+        self.seed_preset(&GOSPER_GLIDER_GUN, top_row, left_col)
+    }
+
+    /// Sets the alive cells of the pattern with its top-left corner at
+    /// `(row, column)`, leaving every other cell untouched
+    #[cfg(feature = "alloc")]
+    pub fn place_pattern(
+        &mut self,
+        grid: &PatternGrid,
+        row: usize,
+        column: usize,
+    ) -> Result<(), PlacementError> {
+        Self::check_placement(grid.height, grid.width, row, column)?;
+        for (row_offset, cells) in grid.cells.iter().enumerate() {
+            for (col_offset, &alive) in cells.iter().enumerate() {
+                if alive {
+                    self.set_cell(row + row_offset, column + col_offset, State::Alive);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that a `rows` x `cols` pattern fits with its top-left corner at
+    /// `(top_row, left_col)`
+    fn check_placement(
+        rows: usize,
+        cols: usize,
+        top_row: usize,
+        left_col: usize,
+    ) -> Result<(), PlacementError> {
+        if rows > H || cols > W {
+            return Err(PlacementError::UniverseTooSmall {
+                required_rows: rows,
+                required_cols: cols,
+                available_rows: H,
+                available_cols: W,
+            });
+        }
+        if top_row > H - rows || left_col > W - cols {
+            return Err(PlacementError::OutOfBounds);
+        }
+        Ok(())
+    }
+
+    /// Sets the given offsets alive, leaving every other cell untouched
+    fn seed_preset(
+        &mut self,
+        cells: &[(usize, usize)],
+        top_row: usize,
+        left_col: usize,
+    ) -> Result<(), PlacementError> {
+        let required_rows = cells.iter().map(|&(row, _)| row + 1).max().unwrap_or(0);
+        let required_cols = cells
+            .iter()
+            .map(|&(_, column)| column + 1)
+            .max()
+            .unwrap_or(0);
+        Self::check_placement(required_rows, required_cols, top_row, left_col)?;
+        for &(row, column) in cells.iter() {
+            self.set_cell(top_row + row, left_col + column, State::Alive);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed_glider() {
+        let mut universe = Universe::<5, 5>::new();
+        assert_eq!(universe.seed_glider(0, 0), Ok(()));
+        assert_eq!(universe.count_alive(), 5);
+    }
+
+    #[test]
+    fn test_glider_moves_diagonally() {
+        let mut universe = Universe::<10, 10>::new();
+        universe.seed_glider(1, 1).unwrap();
+        let mut expected = Universe::<10, 10>::new();
+        expected.seed_glider(2, 2).unwrap();
+
+        for _ in 0..4 {
+            universe.evolve();
+        }
+        assert_eq!(universe.state_grid(), expected.state_grid());
+    }
+
+    #[test]
+    fn test_seed_out_of_bounds() {
+        let mut universe = Universe::<5, 5>::new();
+        assert_eq!(universe.seed_glider(3, 0), Err(PlacementError::OutOfBounds));
+        assert_eq!(
+            universe.seed_blinker(0, 3),
+            Err(PlacementError::OutOfBounds)
+        );
+        assert_eq!(universe.seed_beacon(2, 2), Err(PlacementError::OutOfBounds));
+        assert_eq!(
+            universe.seed_glider(usize::MAX, 0),
+            Err(PlacementError::OutOfBounds)
+        );
+        assert_eq!(
+            universe.seed_block(0, usize::MAX),
+            Err(PlacementError::OutOfBounds)
+        );
+        assert!(universe.is_empty());
+        assert_eq!(universe.seed_blinker(4, 2), Ok(()));
+    }
+
+    #[test]
+    fn test_block_is_stable() {
+        let mut universe = Universe::<6, 6>::new();
+        universe.seed_block(2, 2).unwrap();
+        assert_eq!(universe.count_alive(), 4);
+        assert!(universe.is_stable());
+    }
+
+    #[test]
+    fn test_oscillators_have_period_two() {
+        let mut universe = Universe::<20, 8>::new();
+        universe.seed_blinker(1, 1).unwrap();
+        universe.seed_toad(2, 6).unwrap();
+        universe.seed_beacon(2, 13).unwrap();
+        let initial = universe.state_grid();
+
+        universe.evolve();
+        assert_ne!(universe.state_grid(), initial);
+        universe.evolve();
+        assert_eq!(universe.state_grid(), initial);
+    }
+
+    #[test]
+    fn test_gosper_glider_gun_needs_room() {
+        let mut universe = Universe::<30, 9>::new();
+        assert_eq!(
+            universe.seed_gosper_glider_gun(0, 0),
+            Err(PlacementError::UniverseTooSmall {
+                required_rows: 9,
+                required_cols: 36,
+                available_rows: 9,
+                available_cols: 30,
+            })
+        );
+        let mut universe = Universe::<36, 9>::new();
+        assert_eq!(
+            universe.seed_gosper_glider_gun(0, 1),
+            Err(PlacementError::OutOfBounds)
+        );
+        assert_eq!(universe.seed_gosper_glider_gun(0, 0), Ok(()));
+        assert_eq!(universe.count_alive(), 36);
+    }
+
+    #[test]
+    fn test_gosper_glider_gun_emits_gliders() {
+        let mut universe = Universe::<60, 40>::new();
+        universe.seed_gosper_glider_gun(1, 1).unwrap();
+        let gun: [(usize, usize); 36] =
+            GOSPER_GLIDER_GUN.map(|(row, column)| (row + 1, column + 1));
+        let first_glider = [(10, 24), (11, 25), (11, 26), (12, 24), (12, 25)];
+        let second_glider = [(18, 31), (18, 33), (19, 32), (19, 33), (20, 32)];
+
+        for _ in 0..30 {
+            universe.evolve();
+        }
+        // The gun is back to its initial phase and a glider has left it
+        let expected = gun.iter().chain(first_glider.iter()).copied();
+        assert!(universe.alive_cells().eq(expected));
+
+        for _ in 0..30 {
+            universe.evolve();
+        }
+        // The first glider has moved on and another one has been fired
+        let expected = gun
+            .iter()
+            .chain(first_glider.iter())
+            .chain(second_glider.iter())
+            .copied();
+        assert!(universe.alive_cells().eq(expected));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_place_pattern() {
+        let mut pattern = PatternGrid::new(3, 2);
+        pattern.cells[0][0] = true;
+        pattern.cells[1][2] = true;
+
+        let mut universe = Universe::<5, 4>::new();
+        universe.set_cell(3, 4, State::Alive);
+        assert_eq!(universe.place_pattern(&pattern, 2, 2), Ok(()));
+        assert!(universe.alive_cells().eq([(2, 2), (3, 4)].into_iter()));
+
+        assert_eq!(
+            universe.place_pattern(&pattern, 3, 0),
+            Err(PlacementError::OutOfBounds)
+        );
+        assert_eq!(
+            universe.place_pattern(&PatternGrid::new(6, 1), 0, 0),
+            Err(PlacementError::UniverseTooSmall {
+                required_rows: 1,
+                required_cols: 6,
+                available_rows: 4,
+                available_cols: 5,
+            })
+        );
+    }
+}
@@ -0,0 +1,231 @@
+use crate::{Neighborhood, State, Universe};
+
+/// A minimal xorshift64 generator for seeding universes without an external
+/// random number crate
+///
+/// Not suitable for cryptography. The same seed always yields the same
+/// sequence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct XorShift64(u64);
+
+impl XorShift64 {
+    /// Creates a generator from the seed
+    ///
+    /// Xorshift gets stuck at zero, so a zero seed is replaced by a fixed
+    /// non-zero one.
+    pub const fn new(seed: u64) -> Self {
+        XorShift64(if seed == 0 {
+            0x9E37_79B9_7F4A_7C15
+        } else {
+            seed
+        })
+    }
+
+    /// Returns the next value in the sequence
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl rand_core::RngCore for XorShift64 {
+    fn next_u32(&mut self) -> u32 {
+        (XorShift64::next_u64(self) >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        XorShift64::next_u64(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Creates a universe whose cells are alive with probability
+    /// `probability / 255`, drawn from an [`XorShift64`] seeded with `seed`
+    ///
+    /// A probability of 0 gives an empty universe and 255 a full one.
+    pub fn from_seed_deterministic(seed: u64, probability: u8) -> Self {
+        let mut rng = XorShift64::new(seed);
+        let mut universe = Self::new();
+        for cell in universe.grid.iter_mut().flatten() {
+            if ((rng.next_u64() >> 32) % 255) < probability as u64 {
+                cell.set_state(State::Alive);
+            }
+        }
+        universe
+    }
+
+    /// Sets each cell alive with probability `alive_probability`, drawing from
+    /// any `rand_core::RngCore`
+    ///
+    /// Probabilities at or below 0.0 leave every cell dead and those at or
+    /// above 1.0 make every cell alive.
+    #[cfg(feature = "rand_core")]
+    pub fn random_fill<R: rand_core::RngCore>(&mut self, rng: &mut R, alive_probability: f32) {
+        self.evolved = false;
+        for cell in self.grid.iter_mut().flatten() {
+            let state = if sample(rng) < alive_probability {
+                State::Alive
+            } else {
+                State::Dead
+            };
+            *cell = crate::Cell::new().with_state(state);
+        }
+    }
+
+    /// Sets each cell alive with probability one half
+    #[cfg(feature = "rand_core")]
+    pub fn random_fill_uniform<R: rand_core::RngCore>(&mut self, rng: &mut R) {
+        self.random_fill(rng, 0.5);
+    }
+
+    /// Evolves the universe by its rule, except that each birth is cancelled
+    /// with probability `birth_noise` and each survival with probability
+    /// `death_noise`
+    ///
+    /// With both noises at 0.0 this is `evolve()`, and the generator is only
+    /// drawn from for cells the rule would bring or keep alive.
+    #[cfg(feature = "rand_core")]
+    pub fn evolve_stochastic<R: rand_core::RngCore>(
+        &mut self,
+        rng: &mut R,
+        birth_noise: f32,
+        death_noise: f32,
+    ) {
+        let rule = self.rule;
+        self.apply_rule(|state, live_neighbors| {
+            let next = rule.next_state(state, live_neighbors);
+            let noise = match state {
+                State::Dead => birth_noise,
+                State::Alive => death_noise,
+            };
+            if next == State::Alive && sample(rng) < noise {
+                State::Dead
+            } else {
+                next
+            }
+        });
+    }
+}
+
+/// Returns a uniformly distributed value in `[0, 1)`
+#[cfg(feature = "rand_core")]
+fn sample<R: rand_core::RngCore>(rng: &mut R) -> f32 {
+    // The top 24 bits fit an f32 mantissa exactly
+    (rng.next_u32() >> 8) as f32 / (1 << 24) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "rand_core")]
+    use rand::rngs::StdRng;
+    #[cfg(feature = "rand_core")]
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn test_deterministic_seeding() {
+        let a = Universe::<16, 16>::from_seed_deterministic(1, 128);
+        assert_eq!(a, Universe::<16, 16>::from_seed_deterministic(1, 128));
+        assert_ne!(a, Universe::<16, 16>::from_seed_deterministic(2, 128));
+        assert!(Universe::<16, 16>::from_seed_deterministic(1, 0).is_empty());
+        assert!(Universe::<16, 16>::from_seed_deterministic(1, 255).is_full());
+    }
+
+    #[test]
+    fn test_xorshift_uniformity() {
+        let universe = Universe::<100, 100>::from_seed_deterministic(70, 128);
+        let alive = universe.count_alive();
+        assert!(alive.abs_diff(5020) < 150, "{} cells alive", alive);
+
+        let mut rng = XorShift64::new(0);
+        assert_ne!(rng.next_u64(), 0);
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_random_fill_extremes() {
+        let mut rng = StdRng::seed_from_u64(69);
+        let mut universe = Universe::<16, 16>::new();
+        universe.random_fill(&mut rng, 1.0);
+        assert!(universe.is_full());
+        universe.random_fill(&mut rng, 0.0);
+        assert!(universe.is_empty());
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_random_fill_density() {
+        let mut rng = StdRng::seed_from_u64(69);
+        let mut universe = Universe::<100, 100>::new();
+        universe.random_fill(&mut rng, 0.5);
+        let alive = universe.count_alive();
+        assert!(alive.abs_diff(5000) < 150, "{} cells alive", alive);
+
+        universe.random_fill_uniform(&mut rng);
+        assert!(universe.count_alive().abs_diff(5000) < 150);
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_evolve_stochastic_without_noise_matches_evolve() {
+        let mut rng = StdRng::seed_from_u64(86);
+        let mut noisy = Universe::<16, 16>::from_seed_deterministic(86, 100);
+        let mut exact = noisy.clone();
+        for _ in 0..10 {
+            noisy.evolve_stochastic(&mut rng, 0.0, 0.0);
+            exact.evolve();
+            assert_eq!(noisy, exact);
+        }
+        assert_eq!(noisy.generation(), 10);
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_evolve_stochastic_full_noise() {
+        let mut rng = StdRng::seed_from_u64(86);
+        let start = Universe::<16, 16>::from_seed_deterministic(86, 100);
+
+        // Without births the population can only shrink
+        let mut universe = start.clone();
+        let mut population = universe.count_alive();
+        for _ in 0..10 {
+            universe.evolve_stochastic(&mut rng, 1.0, 0.0);
+            assert!(universe.count_alive() <= population);
+            population = universe.count_alive();
+        }
+
+        let mut universe = start.clone();
+        universe.evolve_stochastic(&mut rng, 0.0, 1.0);
+        let mut survivors = start.clone();
+        survivors.evolve();
+        assert!(universe.intersect(&start).is_empty());
+        assert_eq!(universe, survivors.intersect(&start.invert()));
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn test_moderate_noise_decays_patterns() {
+        let mut rng = StdRng::seed_from_u64(86);
+        let mut universe = Universe::<32, 32>::from_seed_deterministic(86, 100);
+        let initial = universe.count_alive();
+        for _ in 0..50 {
+            universe.evolve_stochastic(&mut rng, 0.3, 0.3);
+        }
+        assert!(universe.count_alive() < initial / 2);
+    }
+}
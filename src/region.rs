@@ -0,0 +1,263 @@
+use core::fmt;
+
+use crate::{DynamicUniverse, Neighborhood, State, Universe};
+
+/// Errors returned when a rectangle cannot be cut out of a universe
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CropError {
+    /// The rectangle extends past the edge of the grid
+    OutOfBounds {
+        top: usize,
+        left: usize,
+        height: usize,
+        width: usize,
+        universe_height: usize,
+        universe_width: usize,
+    },
+    /// Either the width or the height of the rectangle is zero
+    ZeroDimension,
+}
+
+impl fmt::Display for CropError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CropError::OutOfBounds {
+                top,
+                left,
+                height,
+                width,
+                universe_height,
+                universe_width,
+            } => write!(
+                f,
+                "a {}x{} rectangle at ({}, {}) extends past the edge of a {}x{} universe",
+                width, height, top, left, universe_width, universe_height
+            ),
+            CropError::ZeroDimension => write!(f, "crop dimensions must be non-zero"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CropError {}
+
+/// Errors returned when a universe cannot be embedded in another
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum EmbedError {
+    /// The embedded universe would extend past the edge of the grid
+    OutOfBounds,
+}
+
+impl fmt::Display for EmbedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmbedError::OutOfBounds => write!(f, "pattern extends past the edge of the grid"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EmbedError {}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Copies the cells of rows `top..top + height` and columns
+    /// `left..left + width` into a new universe at generation 0
+    pub fn crop(
+        &self,
+        top: usize,
+        left: usize,
+        height: usize,
+        width: usize,
+    ) -> Result<DynamicUniverse, CropError> {
+        if height == 0 || width == 0 {
+            return Err(CropError::ZeroDimension);
+        }
+        if height > H || top > H - height || width > W || left > W - width {
+            return Err(CropError::OutOfBounds {
+                top,
+                left,
+                height,
+                width,
+                universe_height: H,
+                universe_width: W,
+            });
+        }
+        let mut cropped =
+            DynamicUniverse::new(width, height).map_err(|_| CropError::ZeroDimension)?;
+        for row in 0..height {
+            for column in 0..width {
+                if self.grid[top + row][left + column].is_alive() {
+                    cropped.set_cell(row, column, State::Alive);
+                }
+            }
+        }
+        Ok(cropped)
+    }
+
+    /// Sets the alive cells of `pattern` alive with its top-left corner at
+    /// `(top_row, left_col)`, leaving every other cell untouched
+    ///
+    /// [`place_pattern`](Self::place_pattern) does the same for a `PatternGrid`.
+    pub fn embed(
+        &mut self,
+        pattern: &DynamicUniverse,
+        top_row: usize,
+        left_col: usize,
+    ) -> Result<(), EmbedError> {
+        self.embed_with(pattern, top_row, left_col, false)
+    }
+
+    /// Copies every cell of `pattern`, alive or dead, with its top-left corner
+    /// at `(top_row, left_col)`
+    pub fn embed_overwrite(
+        &mut self,
+        pattern: &DynamicUniverse,
+        top_row: usize,
+        left_col: usize,
+    ) -> Result<(), EmbedError> {
+        self.embed_with(pattern, top_row, left_col, true)
+    }
+
+    fn embed_with(
+        &mut self,
+        pattern: &DynamicUniverse,
+        top_row: usize,
+        left_col: usize,
+        overwrite: bool,
+    ) -> Result<(), EmbedError> {
+        if pattern.height() > H
+            || top_row > H - pattern.height()
+            || pattern.width() > W
+            || left_col > W - pattern.width()
+        {
+            return Err(EmbedError::OutOfBounds);
+        }
+        self.evolved = false;
+        for row in 0..pattern.height() {
+            for column in 0..pattern.width() {
+                let cell = &mut self.grid[top_row + row][left_col + column];
+                if pattern.is_alive(row, column) {
+                    cell.set_state(State::Alive);
+                } else if overwrite {
+                    cell.set_state(State::Dead);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crop_center() {
+        let mut universe = Universe::<5, 5>::new();
+        universe.seed_glider(1, 1).unwrap();
+        universe.set_cell(0, 0, State::Alive);
+        let cropped = universe.crop(1, 1, 3, 3).unwrap();
+        assert_eq!((cropped.width(), cropped.height()), (3, 3));
+        for row in 0..3 {
+            for column in 0..3 {
+                assert_eq!(
+                    cropped.is_alive(row, column),
+                    universe.is_alive(row + 1, column + 1)
+                );
+            }
+        }
+        assert_eq!(cropped.count_alive(), 5);
+    }
+
+    #[test]
+    fn test_crop_out_of_bounds() {
+        let universe = Universe::<5, 4>::new();
+        assert_eq!(
+            universe.crop(2, 3, 3, 2),
+            Err(CropError::OutOfBounds {
+                top: 2,
+                left: 3,
+                height: 3,
+                width: 2,
+                universe_height: 4,
+                universe_width: 5,
+            })
+        );
+        assert_eq!(universe.crop(0, 0, 0, 2), Err(CropError::ZeroDimension));
+        assert!(matches!(
+            universe.crop(usize::MAX, 0, 1, 1),
+            Err(CropError::OutOfBounds { .. })
+        ));
+        assert!(matches!(
+            universe.crop(0, 1, 1, usize::MAX),
+            Err(CropError::OutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn test_crop_single_cell() {
+        let mut universe = Universe::<5, 5>::new();
+        universe.set_cell(4, 4, State::Alive);
+        for _ in 0..3 {
+            universe.evolve();
+        }
+        universe.set_cell(4, 4, State::Alive);
+        let cropped = universe.crop(4, 4, 1, 1).unwrap();
+        assert_eq!((cropped.width(), cropped.height()), (1, 1));
+        assert!(cropped.is_alive(0, 0));
+        assert_eq!(cropped.generation(), 0);
+    }
+
+    fn dynamic_glider() -> DynamicUniverse {
+        let mut glider = DynamicUniverse::new(3, 3).unwrap();
+        for (row, column) in [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)] {
+            glider.set_cell(row, column, State::Alive);
+        }
+        glider
+    }
+
+    #[test]
+    fn test_embed_glider() {
+        let mut universe = Universe::<10, 10>::new();
+        universe.embed(&dynamic_glider(), 4, 5).unwrap();
+        let mut expected = Universe::<10, 10>::new();
+        expected.seed_glider(4, 5).unwrap();
+        assert_eq!(universe, expected);
+        assert_eq!(
+            universe.embed(&dynamic_glider(), 8, 0),
+            Err(EmbedError::OutOfBounds)
+        );
+        assert_eq!(
+            universe.embed(&dynamic_glider(), usize::MAX, 0),
+            Err(EmbedError::OutOfBounds)
+        );
+        assert_eq!(
+            universe.embed_overwrite(&dynamic_glider(), 0, usize::MAX),
+            Err(EmbedError::OutOfBounds)
+        );
+        assert_eq!(universe.count_alive(), 5);
+    }
+
+    #[test]
+    fn test_embed_overwrite_clears_cells() {
+        let mut universe = Universe::<6, 6>::new();
+        universe.fill(State::Alive);
+        let dead = DynamicUniverse::new(2, 3).unwrap();
+        universe.embed_overwrite(&dead, 1, 2).unwrap();
+        assert_eq!(universe.count_alive(), 30);
+        assert!(!universe.is_alive(3, 3));
+        assert!(universe.is_alive(4, 3));
+    }
+
+    #[test]
+    fn test_embed_keeps_alive_cells() {
+        let mut universe = Universe::<6, 6>::new();
+        universe.set_cell(0, 0, State::Alive);
+        universe.set_cell(1, 1, State::Alive);
+        universe.embed(&dynamic_glider(), 0, 0).unwrap();
+        assert!(universe.is_alive(0, 0) && universe.is_alive(1, 1));
+        assert_eq!(universe.count_alive(), 7);
+    }
+}
@@ -0,0 +1,353 @@
+use core::fmt;
+use core::ops::Deref;
+use core::str::FromStr;
+
+use crate::State;
+
+/// Errors returned when parsing a rule in B/S notation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RuleParseError {
+    /// The birth and survival parts are not separated by `/`
+    MissingSlash,
+    /// The birth part does not start with `B`
+    MissingBPrefix,
+    /// The survival part does not start with `S`
+    MissingSPrefix,
+    /// A character other than a digit was found in a neighbor count list
+    InvalidCharacter(char),
+    /// A neighbor count greater than 8 was found
+    CountOutOfRange(u8),
+    /// A neighbor count appears more than once in the same list
+    DuplicateCount(u8),
+}
+
+impl fmt::Display for RuleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleParseError::MissingSlash => write!(f, "missing '/' between birth and survival"),
+            RuleParseError::MissingBPrefix => write!(f, "birth counts must start with 'B'"),
+            RuleParseError::MissingSPrefix => write!(f, "survival counts must start with 'S'"),
+            RuleParseError::InvalidCharacter(c) => write!(f, "invalid character '{}'", c),
+            RuleParseError::CountOutOfRange(n) => {
+                write!(f, "neighbor count {} is out of range 0-8", n)
+            }
+            RuleParseError::DuplicateCount(n) => write!(f, "neighbor count {} is repeated", n),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RuleParseError {}
+
+/// An outer-totalistic rule for two-state cellular automata
+///
+/// `birth[n]` is true if a dead cell with `n` live neighbors comes alive and
+/// `survival[n]` is true if an alive cell with `n` live neighbors stays alive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RuleSet {
+    pub birth: [bool; 9],
+    pub survival: [bool; 9],
+}
+
+impl RuleSet {
+    /// Conway's Game of Life (B3/S23)
+    pub const CONWAY: RuleSet = RuleSet::from_counts(&[3], &[2, 3]);
+    /// HighLife (B36/S23), which has a small replicator
+    pub const HIGH_LIFE: RuleSet = RuleSet::from_counts(&[3, 6], &[2, 3]);
+    /// Day & Night (B3678/S34678), symmetric under inversion of alive and dead cells
+    pub const DAY_AND_NIGHT: RuleSet = RuleSet::from_counts(&[3, 6, 7, 8], &[3, 4, 6, 7, 8]);
+    /// Seeds (B2/S), where every cell dies after one generation
+    pub const SEEDS: RuleSet = RuleSet::from_counts(&[2], &[]);
+    /// Maze (B3/S12345)
+    pub const MAZE: RuleSet = RuleSet::from_counts(&[3], &[1, 2, 3, 4, 5]);
+    /// Life without Death (B3/S012345678), where alive cells never die
+    pub const LIFE_WITHOUT_DEATH: RuleSet =
+        RuleSet::from_counts(&[3], &[0, 1, 2, 3, 4, 5, 6, 7, 8]);
+    /// Replicator (B1357/S1357), where every pattern is eventually replicated
+    pub const REPLICATOR: RuleSet = RuleSet::from_counts(&[1, 3, 5, 7], &[1, 3, 5, 7]);
+    /// Coral (B3/S45678)
+    pub const CORAL: RuleSet = RuleSet::from_counts(&[3], &[4, 5, 6, 7, 8]);
+    /// Gnarl (B1/S1)
+    pub const GNARL: RuleSet = RuleSet::from_counts(&[1], &[1]);
+    /// Walled Cities (B2345/S45678)
+    pub const WALLED_CITIES: RuleSet = RuleSet::from_counts(&[2, 3, 4, 5], &[4, 5, 6, 7, 8]);
+    /// B2/S34H, a rule for the six neighbors of a `HexUniverse`
+    pub const HEX_LIFE: RuleSet = RuleSet::from_counts(&[2], &[3, 4]);
+
+    /// Builds a rule from lists of birth and survival neighbor counts
+    ///
+    /// Counts greater than 8 are ignored.
+    pub const fn from_counts(birth: &[u8], survival: &[u8]) -> Self {
+        RuleSet {
+            birth: counts_to_flags(birth),
+            survival: counts_to_flags(survival),
+        }
+    }
+
+    /// Parses a rule in B/S notation such as `"B3/S23"`
+    pub fn parse(s: &str) -> Result<RuleSet, RuleParseError> {
+        let (birth, survival) = s
+            .trim()
+            .split_once('/')
+            .ok_or(RuleParseError::MissingSlash)?;
+        let birth = birth
+            .strip_prefix(['B', 'b'])
+            .ok_or(RuleParseError::MissingBPrefix)?;
+        let survival = survival
+            .strip_prefix(['S', 's'])
+            .ok_or(RuleParseError::MissingSPrefix)?;
+        Ok(RuleSet {
+            birth: parse_counts(birth)?,
+            survival: parse_counts(survival)?,
+        })
+    }
+
+    /// Returns the rule in B/S notation, e.g. `"B3/S23"`
+    pub fn to_bs_string(&self) -> RuleString {
+        let mut string = RuleString {
+            bytes: [0; RuleString::CAPACITY],
+            len: 0,
+        };
+        string.push(b'B');
+        string.push_counts(&self.birth);
+        string.push(b'/');
+        string.push(b'S');
+        string.push_counts(&self.survival);
+        string
+    }
+
+    /// Returns the next state of a cell in the given state with `live_neighbors` alive neighbors
+    pub fn next_state(&self, state: State, live_neighbors: u8) -> State {
+        let counts = match state {
+            State::Dead => &self.birth,
+            State::Alive => &self.survival,
+        };
+        match counts.get(live_neighbors as usize) {
+            Some(true) => State::Alive,
+            _ => State::Dead,
+        }
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        RuleSet::CONWAY
+    }
+}
+
+impl FromStr for RuleSet {
+    type Err = RuleParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RuleSet::parse(s)
+    }
+}
+
+impl fmt::Display for RuleSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_bs_string())
+    }
+}
+
+/// A rule in B/S notation, stored inline so it is available without `alloc`
+///
+/// Created by `RuleSet::to_bs_string()`. Dereferences to `str`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuleString {
+    bytes: [u8; RuleString::CAPACITY],
+    len: u8,
+}
+
+impl RuleString {
+    /// Length of the longest rule, `"B012345678/S012345678"`
+    const CAPACITY: usize = 21;
+
+    /// Returns the rule as a string slice
+    pub fn as_str(&self) -> &str {
+        // Only ASCII bytes are ever pushed
+        core::str::from_utf8(&self.bytes[..self.len as usize]).unwrap_or_default()
+    }
+
+    fn push(&mut self, byte: u8) {
+        self.bytes[self.len as usize] = byte;
+        self.len += 1;
+    }
+
+    fn push_counts(&mut self, flags: &[bool; 9]) {
+        for (count, _) in flags.iter().enumerate().filter(|(_, &flag)| flag) {
+            self.push(b'0' + count as u8);
+        }
+    }
+}
+
+impl Deref for RuleString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for RuleString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for RuleString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq<str> for RuleString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for RuleString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+const fn counts_to_flags(counts: &[u8]) -> [bool; 9] {
+    let mut flags = [false; 9];
+    let mut index = 0;
+    while index < counts.len() {
+        if counts[index] < 9 {
+            flags[counts[index] as usize] = true;
+        }
+        index += 1;
+    }
+    flags
+}
+
+fn parse_counts(s: &str) -> Result<[bool; 9], RuleParseError> {
+    let mut flags = [false; 9];
+    for c in s.chars() {
+        let count = c.to_digit(10).ok_or(RuleParseError::InvalidCharacter(c))? as u8;
+        if count > 8 {
+            return Err(RuleParseError::CountOutOfRange(count));
+        }
+        if flags[count as usize] {
+            return Err(RuleParseError::DuplicateCount(count));
+        }
+        flags[count as usize] = true;
+    }
+    Ok(flags)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Universe;
+
+    #[test]
+    fn test_parse_conway() {
+        assert_eq!(RuleSet::parse("B3/S23"), Ok(RuleSet::CONWAY));
+        assert_eq!("b3/s32".parse(), Ok(RuleSet::CONWAY));
+    }
+
+    #[test]
+    fn test_parse_empty_survival() {
+        let seeds = RuleSet::parse("B2/S").unwrap();
+        assert_eq!(
+            seeds.birth,
+            [false, false, true, false, false, false, false, false, false]
+        );
+        assert_eq!(seeds.survival, [false; 9]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(RuleSet::parse("B3S23"), Err(RuleParseError::MissingSlash));
+        assert_eq!(RuleSet::parse("3/S23"), Err(RuleParseError::MissingBPrefix));
+        assert_eq!(RuleSet::parse("B3/23"), Err(RuleParseError::MissingSPrefix));
+        assert_eq!(
+            RuleSet::parse("B3/S2x"),
+            Err(RuleParseError::InvalidCharacter('x'))
+        );
+        assert_eq!(
+            RuleSet::parse("B9/S23"),
+            Err(RuleParseError::CountOutOfRange(9))
+        );
+        assert_eq!(
+            RuleSet::parse("B33/S23"),
+            Err(RuleParseError::DuplicateCount(3))
+        );
+    }
+
+    #[test]
+    fn test_next_state() {
+        let conway = RuleSet::CONWAY;
+        assert_eq!(conway.next_state(State::Dead, 3), State::Alive);
+        assert_eq!(conway.next_state(State::Dead, 2), State::Dead);
+        assert_eq!(conway.next_state(State::Alive, 2), State::Alive);
+        assert_eq!(conway.next_state(State::Alive, 4), State::Dead);
+        assert_eq!(conway.next_state(State::Alive, 24), State::Dead);
+    }
+
+    #[test]
+    fn test_named_rules_round_trip() {
+        let rules = [
+            (RuleSet::CONWAY, "B3/S23"),
+            (RuleSet::HIGH_LIFE, "B36/S23"),
+            (RuleSet::DAY_AND_NIGHT, "B3678/S34678"),
+            (RuleSet::SEEDS, "B2/S"),
+            (RuleSet::MAZE, "B3/S12345"),
+            (RuleSet::LIFE_WITHOUT_DEATH, "B3/S012345678"),
+            (RuleSet::REPLICATOR, "B1357/S1357"),
+            (RuleSet::CORAL, "B3/S45678"),
+            (RuleSet::GNARL, "B1/S1"),
+            (RuleSet::WALLED_CITIES, "B2345/S45678"),
+        ];
+        for (rule, notation) in rules.iter() {
+            assert_eq!(rule.to_bs_string(), *notation);
+            assert_eq!(RuleSet::parse(notation), Ok(*rule));
+        }
+    }
+
+    #[test]
+    fn test_longest_rule_string() {
+        let all = RuleSet::parse("B012345678/S012345678").unwrap();
+        assert_eq!(all.to_bs_string(), "B012345678/S012345678");
+        assert_eq!(
+            RuleSet::parse("B/S").unwrap().to_bs_string().as_str(),
+            "B/S"
+        );
+    }
+
+    #[test]
+    fn test_seeds_kills_a_single_cell() {
+        let mut universe = Universe::<5, 5>::with_rule(RuleSet::SEEDS);
+        universe.set_cell(2, 2, State::Alive);
+        universe.evolve();
+        assert!(universe.is_empty());
+    }
+
+    #[test]
+    fn test_high_life_differs_from_conway() {
+        let high_life = RuleSet::parse("B36/S23").unwrap();
+        assert_eq!(high_life, RuleSet::HIGH_LIFE);
+        let mut conway_universe = Universe::<5, 5>::new();
+        let mut high_life_universe = Universe::<5, 5>::with_rule(high_life);
+
+        // The center cell has six live neighbors
+        for &(row, column) in [(1, 1), (1, 2), (1, 3), (3, 1), (3, 2), (3, 3)].iter() {
+            conway_universe.set_cell(row, column, State::Alive);
+            high_life_universe.set_cell(row, column, State::Alive);
+        }
+        conway_universe.evolve();
+        high_life_universe.evolve();
+
+        assert!(!conway_universe.is_alive(2, 2));
+        assert!(high_life_universe.is_alive(2, 2));
+        assert_ne!(
+            conway_universe.state_grid(),
+            high_life_universe.state_grid()
+        );
+    }
+}
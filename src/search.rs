@@ -0,0 +1,102 @@
+use alloc::vec::Vec;
+
+use crate::{DynamicUniverse, Neighborhood, Universe};
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Returns every `(row, column)` at which the top-left corner of `pattern`
+    /// can be placed so that each of its alive cells lands on an alive cell
+    ///
+    /// Dead cells in the pattern match anything. Placements that would extend
+    /// past the edge of the grid are not considered. Results are in row-major
+    /// order.
+    pub fn find_pattern(&self, pattern: &DynamicUniverse) -> Vec<(usize, usize)> {
+        self.find_matches(pattern, false)
+    }
+
+    /// Like [`find_pattern`](Self::find_pattern), but dead cells in the
+    /// pattern must also land on dead cells
+    pub fn find_exact_pattern(&self, pattern: &DynamicUniverse) -> Vec<(usize, usize)> {
+        self.find_matches(pattern, true)
+    }
+
+    fn find_matches(&self, pattern: &DynamicUniverse, exact: bool) -> Vec<(usize, usize)> {
+        let (height, width) = (pattern.height(), pattern.width());
+        if height > H || width > W {
+            return Vec::new();
+        }
+        let matches_at = |top: usize, left: usize| {
+            (0..height).all(|row| {
+                (0..width).all(|column| {
+                    let wanted = pattern.is_alive(row, column);
+                    let found = self.grid[top + row][left + column].is_alive();
+                    if exact {
+                        wanted == found
+                    } else {
+                        !wanted || found
+                    }
+                })
+            })
+        };
+        (0..=H - height)
+            .flat_map(|top| (0..=W - width).map(move |left| (top, left)))
+            .filter(|&(top, left)| matches_at(top, left))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::State;
+
+    fn block() -> DynamicUniverse {
+        let mut block = DynamicUniverse::new(2, 2).unwrap();
+        for (row, column) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            block.set_cell(row, column, State::Alive);
+        }
+        block
+    }
+
+    #[test]
+    fn test_find_block() {
+        let mut universe = Universe::<8, 8>::new();
+        universe.seed_block(2, 3).unwrap();
+        assert_eq!(universe.find_pattern(&block()), vec![(2, 3)]);
+
+        let mut shifted = Universe::<8, 8>::new();
+        shifted.seed_block(5, 0).unwrap();
+        assert_eq!(shifted.find_pattern(&block()), vec![(5, 0)]);
+        assert!(Universe::<8, 8>::new().find_pattern(&block()).is_empty());
+    }
+
+    #[test]
+    fn test_dead_cells_are_wildcards() {
+        let mut universe = Universe::<6, 6>::new();
+        universe.seed_block(1, 1).unwrap();
+        let mut corner = DynamicUniverse::new(2, 2).unwrap();
+        corner.set_cell(0, 0, State::Alive);
+        assert_eq!(
+            universe.find_pattern(&corner),
+            vec![(1, 1), (1, 2), (2, 1), (2, 2)]
+        );
+        // Only the bottom-right cell of the block has dead cells below and right
+        assert_eq!(universe.find_exact_pattern(&corner), vec![(2, 2)]);
+    }
+
+    #[test]
+    fn test_exact_match_requires_dead_surroundings() {
+        let mut bordered = DynamicUniverse::new(4, 4).unwrap();
+        for (row, column) in [(1, 1), (1, 2), (2, 1), (2, 2)] {
+            bordered.set_cell(row, column, State::Alive);
+        }
+        let mut universe = Universe::<8, 8>::new();
+        universe.seed_block(3, 3).unwrap();
+        assert_eq!(universe.find_exact_pattern(&bordered), vec![(2, 2)]);
+
+        universe.set_cell(2, 5, State::Alive);
+        assert!(universe.find_exact_pattern(&bordered).is_empty());
+        assert_eq!(universe.find_pattern(&bordered), vec![(2, 2)]);
+    }
+}
@@ -0,0 +1,290 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Cell, Neighborhood, State, Universe};
+
+/// A cell is written as `{"state": "Alive"}`; its cached neighbor count and
+/// age are not saved
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Cell")]
+struct CellRepr {
+    state: State,
+}
+
+impl Serialize for Cell {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CellRepr {
+            state: self.state(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cell {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = CellRepr::deserialize(deserializer)?;
+        Ok(Cell::new().with_state(repr.state))
+    }
+}
+
+/// A universe is written as
+/// `{"width": W, "height": H, "generation": N, "cells": [[bool]]}`
+///
+/// The rule and boundary condition are not saved, so a deserialized universe
+/// uses the defaults. Deserializing fails if the dimensions differ from `W`
+/// and `H`.
+impl<const W: usize, const H: usize, N: Neighborhood> Serialize for Universe<W, H, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Universe", 4)?;
+        state.serialize_field("width", &W)?;
+        state.serialize_field("height", &H)?;
+        state.serialize_field("generation", &self.generation)?;
+        state.serialize_field("cells", &Rows(&self.grid))?;
+        state.end()
+    }
+}
+
+struct Rows<'a, const W: usize, const H: usize>(&'a [[Cell; W]; H]);
+
+impl<const W: usize, const H: usize> Serialize for Rows<'_, W, H> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut rows = serializer.serialize_seq(Some(H))?;
+        for row in self.0.iter() {
+            rows.serialize_element(&Row(row))?;
+        }
+        rows.end()
+    }
+}
+
+struct Row<'a, const W: usize>(&'a [Cell; W]);
+
+impl<const W: usize> Serialize for Row<'_, W> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut cells = serializer.serialize_seq(Some(W))?;
+        for cell in self.0.iter() {
+            cells.serialize_element(&cell.is_alive())?;
+        }
+        cells.end()
+    }
+}
+
+const FIELDS: &[&str] = &["width", "height", "generation", "cells"];
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Width,
+    Height,
+    Generation,
+    Cells,
+}
+
+impl<'de, const W: usize, const H: usize, N: Neighborhood> Deserialize<'de> for Universe<W, H, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("Universe", FIELDS, UniverseVisitor(PhantomData))
+    }
+}
+
+struct UniverseVisitor<const W: usize, const H: usize, N>(PhantomData<N>);
+
+impl<const W: usize, const H: usize, N: Neighborhood> UniverseVisitor<W, H, N> {
+    fn check_dimension<E: de::Error>(name: &str, found: usize, expected: usize) -> Result<(), E> {
+        if found == expected {
+            Ok(())
+        } else {
+            Err(E::custom(format_args!(
+                "expected {} {}, found {}",
+                name, expected, found
+            )))
+        }
+    }
+}
+
+impl<'de, const W: usize, const H: usize, N: Neighborhood> Visitor<'de>
+    for UniverseVisitor<W, H, N>
+{
+    type Value = Universe<W, H, N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a {}x{} universe", W, H)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let width: usize = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        Self::check_dimension("width", width, W)?;
+        let height: usize = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Self::check_dimension("height", height, H)?;
+        let mut universe = Universe::new();
+        universe.generation = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        seq.next_element_seed(RowsSeed(&mut universe.grid))?
+            .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+        Ok(universe)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut universe = Universe::new();
+        let (mut width, mut height, mut generation, mut cells) = (false, false, false, false);
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Width if !width => {
+                    Self::check_dimension("width", map.next_value()?, W)?;
+                    width = true;
+                }
+                Field::Height if !height => {
+                    Self::check_dimension("height", map.next_value()?, H)?;
+                    height = true;
+                }
+                Field::Generation if !generation => {
+                    universe.generation = map.next_value()?;
+                    generation = true;
+                }
+                Field::Cells if !cells => {
+                    map.next_value_seed(RowsSeed(&mut universe.grid))?;
+                    cells = true;
+                }
+                Field::Width => return Err(de::Error::duplicate_field("width")),
+                Field::Height => return Err(de::Error::duplicate_field("height")),
+                Field::Generation => return Err(de::Error::duplicate_field("generation")),
+                Field::Cells => return Err(de::Error::duplicate_field("cells")),
+            }
+        }
+        for (present, name) in [width, height, generation, cells].into_iter().zip(FIELDS) {
+            if !present {
+                return Err(de::Error::missing_field(name));
+            }
+        }
+        Ok(universe)
+    }
+}
+
+/// The expected length of a sequence, for error messages
+struct Length(usize, &'static str);
+
+impl de::Expected for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.0, self.1)
+    }
+}
+
+/// Reads `[[bool]]` straight into the grid, failing unless there are exactly
+/// `H` rows of `W` cells
+struct RowsSeed<'a, const W: usize, const H: usize>(&'a mut [[Cell; W]; H]);
+
+impl<'de, const W: usize, const H: usize> DeserializeSeed<'de> for RowsSeed<'_, W, H> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, const W: usize, const H: usize> Visitor<'de> for RowsSeed<'_, W, H> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} rows of {} cells", H, W)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        for (index, row) in self.0.iter_mut().enumerate() {
+            seq.next_element_seed(RowSeed(row))?
+                .ok_or_else(|| de::Error::invalid_length(index, &Length(H, "rows")))?;
+        }
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(H + 1, &Length(H, "rows")));
+        }
+        Ok(())
+    }
+}
+
+struct RowSeed<'a, const W: usize>(&'a mut [Cell; W]);
+
+impl<'de, const W: usize> DeserializeSeed<'de> for RowSeed<'_, W> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, const W: usize> Visitor<'de> for RowSeed<'_, W> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a row of {} cells", W)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        for (index, cell) in self.0.iter_mut().enumerate() {
+            let alive: bool = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(index, &Length(W, "cells")))?;
+            if alive {
+                cell.set_state(State::Alive);
+            }
+        }
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(W + 1, &Length(W, "cells")));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_and_cell_json() {
+        assert_eq!(serde_json::to_string(&State::Alive).unwrap(), "\"Alive\"");
+        assert_eq!(
+            serde_json::from_str::<State>("\"Dead\"").unwrap(),
+            State::Dead
+        );
+        let cell = Cell::new_alive();
+        let json = serde_json::to_string(&cell).unwrap();
+        assert_eq!(json, r#"{"state":"Alive"}"#);
+        assert_eq!(serde_json::from_str::<Cell>(&json).unwrap(), cell);
+    }
+
+    #[test]
+    fn test_universe_round_trip() {
+        let mut universe = Universe::<4, 3>::new();
+        universe.seed_blinker(1, 0).unwrap();
+        universe.evolve();
+        let json = serde_json::to_string(&universe).unwrap();
+        assert_eq!(
+            json,
+            concat!(
+                r#"{"width":4,"height":3,"generation":1,"cells":"#,
+                r#"[[false,true,false,false],[false,true,false,false],[false,true,false,false]]}"#
+            )
+        );
+        let decoded: Universe<4, 3> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, universe);
+        assert_eq!(decoded.generation(), 1);
+    }
+
+    #[test]
+    fn test_dimension_mismatch_is_an_error() {
+        let universe = Universe::<4, 3>::new();
+        let json = serde_json::to_string(&universe).unwrap();
+        assert!(serde_json::from_str::<Universe<3, 3>>(&json).is_err());
+        assert!(serde_json::from_str::<Universe<4, 4>>(&json).is_err());
+
+        let short_row = r#"{"width":2,"height":1,"generation":0,"cells":[[true]]}"#;
+        assert!(serde_json::from_str::<Universe<2, 1>>(short_row).is_err());
+        let missing = r#"{"width":2,"height":1,"cells":[[true,false]]}"#;
+        assert!(serde_json::from_str::<Universe<2, 1>>(missing).is_err());
+    }
+}
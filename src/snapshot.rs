@@ -0,0 +1,95 @@
+use crate::{Cell, Neighborhood, State, Universe};
+
+/// The cell states and generation of a universe, saved by
+/// [`Universe::save_snapshot`]
+///
+/// A snapshot holds one `State` per cell and none of the cached neighbor
+/// counts, the rule or the boundary, so it is smaller than a clone. Its size
+/// is part of its type, so it can only be restored into a universe of the
+/// same size:
+///
+/// ```compile_fail
+/// use game_of_life_core::Universe;
+///
+/// let snapshot = Universe::<4, 4>::new().save_snapshot();
+/// Universe::<5, 4>::new().restore_snapshot(&snapshot);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Snapshot<const W: usize, const H: usize> {
+    grid: [[State; W]; H],
+    generation: u64,
+}
+
+impl<const W: usize, const H: usize> Snapshot<W, H> {
+    /// Returns the generation of the universe when it was saved
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns the number of alive cells
+    pub fn count_alive(&self) -> usize {
+        self.grid
+            .iter()
+            .flatten()
+            .filter(|&&state| state == State::Alive)
+            .count()
+    }
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Saves the cell states and the generation
+    pub fn save_snapshot(&self) -> Snapshot<W, H> {
+        Snapshot {
+            grid: self.state_grid(),
+            generation: self.generation,
+        }
+    }
+
+    /// Sets every cell and the generation back to the saved ones, keeping the
+    /// rule and boundary
+    pub fn restore_snapshot(&mut self, snapshot: &Snapshot<W, H>) {
+        for (cells, states) in self.grid.iter_mut().zip(snapshot.grid.iter()) {
+            for (cell, &state) in cells.iter_mut().zip(states.iter()) {
+                *cell = Cell::new().with_state(state);
+            }
+        }
+        self.generation = snapshot.generation;
+        self.evolved = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restore_snapshot() {
+        let mut universe = Universe::<10, 10>::new();
+        universe.seed_glider(0, 0).unwrap();
+        universe.step_n(5);
+        let at_five = universe.clone();
+        let snapshot = universe.save_snapshot();
+        universe.step_n(10);
+        assert_ne!(universe, at_five);
+
+        universe.restore_snapshot(&snapshot);
+        assert_eq!(universe.generation(), 5);
+        assert_eq!(universe, at_five);
+    }
+
+    #[test]
+    fn test_snapshot_accessors() {
+        let mut universe = Universe::<6, 6>::new();
+        universe.seed_block(1, 1).unwrap();
+        universe.evolve();
+        let snapshot = universe.save_snapshot();
+        assert_eq!(snapshot.generation(), 1);
+        assert_eq!(snapshot.count_alive(), 4);
+
+        let mut other = universe.clone();
+        assert_eq!(other.save_snapshot(), snapshot);
+        other.toggle_cell(0, 0);
+        assert_ne!(other.save_snapshot(), snapshot);
+        assert!(core::mem::size_of::<Snapshot<6, 6>>() < core::mem::size_of::<Universe<6, 6>>());
+    }
+}
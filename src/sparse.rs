@@ -0,0 +1,205 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use core::fmt;
+
+use crate::{Neighborhood, RuleSet, State, Universe};
+
+/// Error returned when a sparse universe has alive cells outside a fixed grid
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CoordinateOutOfRange {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl fmt::Display for CoordinateOutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "alive cell ({}, {}) lies outside the grid",
+            self.x, self.y
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CoordinateOutOfRange {}
+
+/// An unbounded universe that only stores its alive cells
+///
+/// Cells are addressed by `(x, y)`, where `x` is the column and `y` the row,
+/// and may be negative. Only cells next to an alive cell are visited by
+/// `evolve()`, so its cost grows with the population rather than the area.
+/// Rules that give birth to cells with no alive neighbors (B0) are therefore
+/// not supported.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SparseUniverse {
+    alive: BTreeSet<(i64, i64)>,
+    rule: RuleSet,
+}
+
+impl SparseUniverse {
+    /// Creates an empty universe following Conway's rules
+    pub fn new() -> Self {
+        Self::with_rule(RuleSet::CONWAY)
+    }
+
+    /// Creates an empty universe that evolves according to the given rule
+    pub fn with_rule(rule: RuleSet) -> Self {
+        SparseUniverse {
+            alive: BTreeSet::new(),
+            rule,
+        }
+    }
+
+    /// Sets the cell alive
+    pub fn set_alive(&mut self, x: i64, y: i64) {
+        self.alive.insert((x, y));
+    }
+
+    /// Sets the cell dead
+    pub fn set_dead(&mut self, x: i64, y: i64) {
+        self.alive.remove(&(x, y));
+    }
+
+    /// Returns true if the cell is alive
+    pub fn is_alive(&self, x: i64, y: i64) -> bool {
+        self.alive.contains(&(x, y))
+    }
+
+    /// Returns the number of alive cells
+    pub fn alive_count(&self) -> usize {
+        self.alive.len()
+    }
+
+    /// Returns an iterator over the `(x, y)` coordinates of alive cells
+    pub fn alive_cells(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        self.alive.iter().copied()
+    }
+
+    /// Evolves the universe
+    pub fn evolve(&mut self) {
+        let mut live_neighbors: BTreeMap<(i64, i64), u8> = BTreeMap::new();
+        for &(x, y) in self.alive.iter() {
+            // Make sure alive cells without alive neighbors are visited too
+            live_neighbors.entry((x, y)).or_insert(0);
+            for delta_y in -1..=1 {
+                for delta_x in -1..=1 {
+                    if delta_x != 0 || delta_y != 0 {
+                        *live_neighbors
+                            .entry((x + delta_x, y + delta_y))
+                            .or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+
+        self.alive = live_neighbors
+            .into_iter()
+            .filter(|&(position, count)| {
+                let state = if self.alive.contains(&position) {
+                    State::Alive
+                } else {
+                    State::Dead
+                };
+                self.rule.next_state(state, count) == State::Alive
+            })
+            .map(|(position, _)| position)
+            .collect();
+    }
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> From<Universe<W, H, N>> for SparseUniverse {
+    fn from(universe: Universe<W, H, N>) -> Self {
+        SparseUniverse {
+            alive: universe
+                .alive_cells()
+                .map(|(row, column)| (column as i64, row as i64))
+                .collect(),
+            rule: universe.rule(),
+        }
+    }
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> TryFrom<SparseUniverse>
+    for Universe<W, H, N>
+{
+    type Error = CoordinateOutOfRange;
+
+    fn try_from(sparse: SparseUniverse) -> Result<Self, Self::Error> {
+        let mut universe = Universe::with_rule(sparse.rule);
+        for &(x, y) in sparse.alive.iter() {
+            if !(0..W as i64).contains(&x) || !(0..H as i64).contains(&y) {
+                return Err(CoordinateOutOfRange { x, y });
+            }
+            universe.set_cell(y as usize, x as usize, State::Alive);
+        }
+        Ok(universe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_and_query_cells() {
+        let mut universe = SparseUniverse::new();
+        universe.set_alive(-1_000_000, 42);
+        universe.set_alive(3, -7);
+        assert!(universe.is_alive(-1_000_000, 42));
+        assert!(!universe.is_alive(0, 0));
+        assert_eq!(universe.alive_count(), 2);
+
+        universe.set_dead(3, -7);
+        assert!(!universe.is_alive(3, -7));
+        assert_eq!(universe.alive_count(), 1);
+    }
+
+    #[test]
+    fn test_lonely_cell_dies() {
+        let mut universe = SparseUniverse::new();
+        universe.set_alive(5, 5);
+        universe.evolve();
+        assert_eq!(universe.alive_count(), 0);
+    }
+
+    #[test]
+    fn test_glider_matches_dense_universe() {
+        let glider = || {
+            let mut universe = Universe::<20, 20>::new();
+            for &(row, column) in [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)].iter() {
+                universe.set_cell(row, column, State::Alive);
+            }
+            universe
+        };
+        let mut dense = glider();
+        let mut sparse = SparseUniverse::from(glider());
+
+        for _ in 0..20 {
+            dense.evolve();
+            sparse.evolve();
+            let expected: BTreeSet<(i64, i64)> = dense
+                .alive_cells()
+                .map(|(row, column)| (column as i64, row as i64))
+                .collect();
+            assert_eq!(sparse.alive, expected);
+        }
+
+        let converted: Universe<20, 20> = sparse.try_into().unwrap();
+        assert_eq!(converted.state_grid(), dense.state_grid());
+    }
+
+    #[test]
+    fn test_try_from_rejects_cells_outside_the_grid() {
+        let mut sparse = SparseUniverse::new();
+        sparse.set_alive(1, 1);
+        sparse.set_alive(-1, 2);
+        let result: Result<Universe<4, 4>, _> = sparse.clone().try_into();
+        assert_eq!(result.err(), Some(CoordinateOutOfRange { x: -1, y: 2 }));
+
+        sparse.set_dead(-1, 2);
+        sparse.set_alive(4, 0);
+        let result: Result<Universe<4, 4>, _> = sparse.try_into();
+        assert_eq!(result.err(), Some(CoordinateOutOfRange { x: 4, y: 0 }));
+    }
+}
@@ -0,0 +1,446 @@
+use core::fmt;
+
+use crate::{BoundingBox, Neighborhood, Universe};
+
+/// Errors returned when counting the cells of a rectangle
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RegionError {
+    /// The rectangle extends past the edge of the grid
+    OutOfBounds {
+        top: usize,
+        left: usize,
+        height: usize,
+        width: usize,
+        universe_height: usize,
+        universe_width: usize,
+    },
+}
+
+impl fmt::Display for RegionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegionError::OutOfBounds {
+                top,
+                left,
+                height,
+                width,
+                universe_height,
+                universe_width,
+            } => write!(
+                f,
+                "a {}x{} rectangle at ({}, {}) extends past the edge of a {}x{} universe",
+                width, height, top, left, universe_width, universe_height
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RegionError {}
+
+/// A summary of a universe, computed by [`Universe::statistics`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UniverseStats {
+    pub alive_count: usize,
+    pub dead_count: usize,
+    /// The fraction of cells that are alive
+    pub density: f32,
+    pub bounding_box: Option<BoundingBox>,
+    pub generation: u64,
+    /// The most live neighbors of any cell, alive or dead
+    pub max_neighbor_count: u8,
+    /// The fewest live neighbors of any alive cell, or 0 if none is alive
+    pub min_neighbor_count_alive: u8,
+    /// The live neighbor counts of every cell added together
+    pub sum_neighbor_counts: u64,
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Returns the number of alive cells in the row
+    ///
+    /// Panics if the row is outside the grid.
+    pub fn alive_in_row(&self, row: usize) -> usize {
+        assert!(
+            row < H,
+            "row {} is out of bounds for a {}x{} universe",
+            row,
+            W,
+            H
+        );
+        self.grid[row].iter().filter(|cell| cell.is_alive()).count()
+    }
+
+    /// Returns the number of alive cells in the column
+    ///
+    /// Panics if the column is outside the grid.
+    pub fn alive_in_col(&self, col: usize) -> usize {
+        assert!(
+            col < W,
+            "column {} is out of bounds for a {}x{} universe",
+            col,
+            W,
+            H
+        );
+        self.grid
+            .iter()
+            .filter(|cells| cells[col].is_alive())
+            .count()
+    }
+
+    /// Returns the number of alive cells in rows `top..top + height` and
+    /// columns `left..left + width`
+    pub fn alive_in_region(
+        &self,
+        top: usize,
+        left: usize,
+        height: usize,
+        width: usize,
+    ) -> Result<usize, RegionError> {
+        if height > H || top > H - height || width > W || left > W - width {
+            return Err(RegionError::OutOfBounds {
+                top,
+                left,
+                height,
+                width,
+                universe_height: H,
+                universe_width: W,
+            });
+        }
+        Ok(self.grid[top..top + height]
+            .iter()
+            .map(|cells| {
+                cells[left..left + width]
+                    .iter()
+                    .filter(|cell| cell.is_alive())
+                    .count()
+            })
+            .sum())
+    }
+
+    /// Returns the fraction of cells in the rectangle that are alive, or 0.0
+    /// if the rectangle is empty
+    pub fn density_in_region(
+        &self,
+        top: usize,
+        left: usize,
+        height: usize,
+        width: usize,
+    ) -> Result<f32, RegionError> {
+        let alive = self.alive_in_region(top, left, height, width)?;
+        let area = height * width;
+        Ok(if area == 0 {
+            0.0
+        } else {
+            alive as f32 / area as f32
+        })
+    }
+
+    /// Returns the number of alive cells in each row, top to bottom
+    pub fn row_population_profile(&self) -> [usize; H] {
+        self.population_profiles().0
+    }
+
+    /// Returns the number of alive cells in each column, left to right
+    pub fn col_population_profile(&self) -> [usize; W] {
+        self.population_profiles().1
+    }
+
+    /// Returns the fraction of cells that are alive, from 0.0 to 1.0
+    pub fn population_density(&self) -> f32 {
+        self.count_alive() as f32 / (W * H) as f32
+    }
+
+    /// Returns the fraction of cells in the row that are alive
+    ///
+    /// Panics if the row is outside the grid.
+    pub fn row_density(&self, row: usize) -> f32 {
+        self.alive_in_row(row) as f32 / W as f32
+    }
+
+    /// Returns the fraction of cells in the column that are alive
+    ///
+    /// Panics if the column is outside the grid.
+    pub fn col_density(&self, col: usize) -> f32 {
+        self.alive_in_col(col) as f32 / H as f32
+    }
+
+    /// Returns the population density as a 16.16 fixed-point number, where
+    /// 65536 means every cell is alive
+    ///
+    /// For targets without hardware floating point.
+    pub fn population_density_fp(&self) -> u32 {
+        (((self.count_alive() as u64) << 16) / (W * H) as u64) as u32
+    }
+
+    /// Returns the binary Shannon entropy of the population density, from 0.0
+    /// for a uniform grid to 1.0 when half of the cells are alive
+    #[cfg(feature = "std")]
+    pub fn entropy(&self) -> f32 {
+        binary_entropy(self.population_density())
+    }
+
+    /// Returns the binary Shannon entropy of the `(2 * radius + 1)`-square
+    /// window centered on a cell
+    ///
+    /// The window follows the boundary condition like a neighbor lookup, so
+    /// cells beyond a dead edge are left out. Panics if the center is outside
+    /// the grid.
+    #[cfg(feature = "std")]
+    pub fn local_entropy(&self, center_row: usize, center_col: usize, radius: usize) -> f32 {
+        Self::check_bounds(center_row, center_col);
+        let radius = radius as isize;
+        let (mut alive, mut total) = (0usize, 0usize);
+        for delta_row in -radius..=radius {
+            let Some(row) = self.boundary.row_offset(center_row, delta_row, H) else {
+                continue;
+            };
+            for delta_col in -radius..=radius {
+                if let Some(column) = self.boundary.col_offset(center_col, delta_col, W) {
+                    alive += self.grid[row][column].is_alive() as usize;
+                    total += 1;
+                }
+            }
+        }
+        binary_entropy(alive as f32 / total as f32)
+    }
+
+    /// Returns population, extent and neighbor statistics gathered in a
+    /// single scan of the grid
+    pub fn statistics(&self) -> UniverseStats {
+        let mut stats = UniverseStats {
+            alive_count: 0,
+            dead_count: 0,
+            density: 0.0,
+            bounding_box: None,
+            generation: self.generation,
+            max_neighbor_count: 0,
+            min_neighbor_count_alive: 0,
+            sum_neighbor_counts: 0,
+        };
+        let mut min_alive = None;
+        for (row, cells) in self.grid.iter().enumerate() {
+            for (column, cell) in cells.iter().enumerate() {
+                let neighbors = self.live_neighbor_count(row, column);
+                stats.max_neighbor_count = stats.max_neighbor_count.max(neighbors);
+                stats.sum_neighbor_counts += neighbors as u64;
+                if !cell.is_alive() {
+                    continue;
+                }
+                stats.alive_count += 1;
+                min_alive = Some(min_alive.map_or(neighbors, |min: u8| min.min(neighbors)));
+                let bounds = stats.bounding_box.get_or_insert(BoundingBox {
+                    min_row: row,
+                    max_row: row,
+                    min_col: column,
+                    max_col: column,
+                });
+                bounds.max_row = row;
+                bounds.min_col = bounds.min_col.min(column);
+                bounds.max_col = bounds.max_col.max(column);
+            }
+        }
+        stats.dead_count = W * H - stats.alive_count;
+        stats.density = stats.alive_count as f32 / (W * H) as f32;
+        stats.min_neighbor_count_alive = min_alive.unwrap_or(0);
+        stats
+    }
+
+    /// Counts the alive cells of every row and column in one pass
+    fn population_profiles(&self) -> ([usize; H], [usize; W]) {
+        let mut rows = [0; H];
+        let mut cols = [0; W];
+        self.for_each_alive(|row, column| {
+            rows[row] += 1;
+            cols[column] += 1;
+        });
+        (rows, cols)
+    }
+}
+
+/// Returns `-p·log2(p) - (1-p)·log2(1-p)`, taking `0·log2(0)` as 0
+#[cfg(feature = "std")]
+fn binary_entropy(p: f32) -> f32 {
+    if p <= 0.0 || p >= 1.0 {
+        return 0.0;
+    }
+    -p * p.log2() - (1.0 - p) * (1.0 - p).log2()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::State;
+
+    #[test]
+    fn test_horizontal_bar_profile() {
+        let mut universe = Universe::<5, 4>::new();
+        for column in 0..5 {
+            universe.set_cell(2, column, State::Alive);
+        }
+        assert_eq!(universe.row_population_profile(), [0, 0, 5, 0]);
+        assert_eq!(universe.col_population_profile(), [1; 5]);
+        assert_eq!(universe.alive_in_row(2), 5);
+        assert_eq!(universe.alive_in_row(1), 0);
+    }
+
+    #[test]
+    fn test_single_cell_profile() {
+        let mut universe = Universe::<4, 6>::new();
+        universe.set_cell(4, 1, State::Alive);
+        assert_eq!(universe.row_population_profile(), [0, 0, 0, 0, 1, 0]);
+        assert_eq!(universe.col_population_profile(), [0, 1, 0, 0]);
+        assert_eq!(universe.alive_in_col(1), 1);
+        assert_eq!(universe.alive_in_col(2), 0);
+    }
+
+    #[test]
+    fn test_block_profile() {
+        let mut universe = Universe::<6, 6>::new();
+        universe.seed_block(2, 3).unwrap();
+        assert_eq!(universe.row_population_profile(), [0, 0, 2, 2, 0, 0]);
+        assert_eq!(universe.col_population_profile(), [0, 0, 0, 2, 2, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 4 is out of bounds for a 4x4 universe")]
+    fn test_alive_in_col_out_of_bounds() {
+        Universe::<4, 4>::new().alive_in_col(4);
+    }
+
+    #[test]
+    fn test_population_density() {
+        let mut universe = Universe::<4, 4>::new();
+        assert_eq!(universe.population_density(), 0.0);
+        for row in 0..2 {
+            for column in 0..4 {
+                universe.set_cell(row, column, State::Alive);
+            }
+        }
+        assert_eq!(universe.population_density(), 0.5);
+        assert_eq!(universe.population_density_fp(), 32768);
+        assert_eq!(universe.row_density(0), 1.0);
+        assert_eq!(universe.row_density(3), 0.0);
+        assert_eq!(universe.col_density(2), 0.5);
+
+        let full = Universe::<4, 4>::from([[true; 4]; 4]);
+        assert_eq!(full.population_density(), 1.0);
+        assert_eq!(full.population_density_fp(), 65536);
+        assert_eq!(Universe::<4, 4>::new().population_density_fp(), 0);
+    }
+
+    #[test]
+    fn test_statistics() {
+        let mut universe = Universe::<8, 8>::new();
+        universe.seed_glider(1, 1).unwrap();
+        universe.seed_block(5, 5).unwrap();
+        universe.step_n(2);
+        let stats = universe.statistics();
+        assert_eq!(stats.alive_count, universe.count_alive());
+        assert_eq!(stats.dead_count, 64 - universe.count_alive());
+        assert_eq!(stats.density, universe.population_density());
+        assert_eq!(stats.bounding_box, universe.bounding_box());
+        assert_eq!(stats.generation, 2);
+
+        let mut counts = [[0; 8]; 8];
+        for (row, counts) in counts.iter_mut().enumerate() {
+            for (column, count) in counts.iter_mut().enumerate() {
+                *count = universe.live_neighbor_count(row, column);
+            }
+        }
+        assert_eq!(
+            stats.max_neighbor_count,
+            *counts.iter().flatten().max().unwrap()
+        );
+        let min_alive = universe
+            .alive_cells()
+            .map(|(row, column)| counts[row][column])
+            .min();
+        assert_eq!(Some(stats.min_neighbor_count_alive), min_alive);
+        let sum: u64 = counts.iter().flatten().map(|&n| n as u64).sum();
+        assert_eq!(stats.sum_neighbor_counts, sum);
+        assert_eq!(universe.statistics(), stats);
+    }
+
+    #[test]
+    fn test_statistics_of_empty_universe() {
+        let stats = Universe::<4, 3>::new().statistics();
+        assert_eq!(stats.alive_count, 0);
+        assert_eq!(stats.dead_count, 12);
+        assert_eq!(stats.bounding_box, None);
+        assert_eq!(stats.min_neighbor_count_alive, 0);
+        assert_eq!(stats.sum_neighbor_counts, 0);
+    }
+
+    #[test]
+    fn test_alive_in_region() {
+        let mut universe = Universe::<5, 5>::new();
+        universe.seed_block(1, 1).unwrap();
+        assert_eq!(
+            universe.alive_in_region(0, 0, 5, 5),
+            Ok(universe.count_alive())
+        );
+        assert_eq!(universe.alive_in_region(1, 1, 2, 2), Ok(4));
+        assert_eq!(universe.alive_in_region(3, 3, 2, 2), Ok(0));
+        assert_eq!(universe.alive_in_region(2, 0, 3, 2), Ok(1));
+        assert_eq!(universe.alive_in_region(0, 2, 5, 0), Ok(0));
+        assert_eq!(universe.density_in_region(1, 1, 2, 4), Ok(0.5));
+        assert_eq!(universe.density_in_region(0, 0, 0, 0), Ok(0.0));
+    }
+
+    #[test]
+    fn test_alive_in_region_out_of_bounds() {
+        let universe = Universe::<5, 5>::new();
+        assert_eq!(
+            universe.alive_in_region(3, 1, 3, 2),
+            Err(RegionError::OutOfBounds {
+                top: 3,
+                left: 1,
+                height: 3,
+                width: 2,
+                universe_height: 5,
+                universe_width: 5,
+            })
+        );
+        assert!(universe.alive_in_region(0, usize::MAX, 1, 2).is_err());
+        assert!(universe.density_in_region(0, 4, 1, 2).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_entropy() {
+        let mut universe = Universe::<4, 4>::new();
+        assert_eq!(universe.entropy(), 0.0);
+        universe.fill(State::Alive);
+        assert_eq!(universe.entropy(), 0.0);
+        for row in 0..2 {
+            for column in 0..4 {
+                universe.set_cell(row, column, State::Dead);
+            }
+        }
+        assert!((universe.entropy() - 1.0).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_local_entropy() {
+        let mut universe = Universe::<6, 6>::new();
+        for row in 1..4 {
+            for column in 1..4 {
+                universe.set_cell(row, column, State::Alive);
+            }
+        }
+        assert_eq!(universe.local_entropy(2, 2, 1), 0.0);
+        assert_eq!(universe.local_entropy(0, 5, 0), 0.0);
+
+        let mut checkerboard = Universe::<6, 6>::new();
+        for row in 0..6 {
+            for column in (row % 2..6).step_by(2) {
+                checkerboard.set_cell(row, column, State::Alive);
+            }
+        }
+        // A 3x3 window holds five cells of one color and four of the other
+        assert!((checkerboard.local_entropy(2, 2, 1) - 1.0).abs() < 0.01);
+        assert!((checkerboard.local_entropy(0, 0, 2) - 1.0).abs() < 0.01);
+    }
+}
@@ -0,0 +1,410 @@
+use core::iter::FusedIterator;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{MooreNeighborhood, Neighborhood, State, Universe};
+
+/// The outcome of [`Universe::step_until`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepResult {
+    /// The condition held once the universe reached this generation
+    ConditionMet { at_generation: u64 },
+    /// The universe evolved `max_steps` times without the condition holding
+    MaxStepsReached,
+}
+
+/// The outcome of [`Universe::run_to_fixpoint`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FixpointResult {
+    /// The cells stopped changing from this generation on
+    StillLife { at_generation: u64 },
+    /// The cells alternated between two states from this generation on
+    Period2Oscillator { at_generation: u64 },
+    /// The universe evolved `max_steps` times without settling
+    MaxStepsReached,
+}
+
+/// Iterator that evolves a `Universe` once per item and yields the states of
+/// the new generation
+///
+/// Holds the universe mutably until dropped. Created by
+/// `Universe::batch_evolve_iter()`.
+pub struct BatchEvolveIter<'a, const W: usize, const H: usize, N: Neighborhood = MooreNeighborhood>
+{
+    universe: &'a mut Universe<W, H, N>,
+    remaining: usize,
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Iterator for BatchEvolveIter<'_, W, H, N> {
+    type Item = [[State; W]; H];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.universe.evolve();
+        Some(self.universe.state_grid())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> ExactSizeIterator
+    for BatchEvolveIter<'_, W, H, N>
+{
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> FusedIterator
+    for BatchEvolveIter<'_, W, H, N>
+{
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Returns an iterator that evolves the universe `count` times, lazily,
+    /// yielding the states after each step
+    ///
+    /// Steps that are never pulled from the iterator are not taken.
+    pub fn batch_evolve_iter(&mut self, count: usize) -> BatchEvolveIter<'_, W, H, N> {
+        BatchEvolveIter {
+            universe: self,
+            remaining: count,
+        }
+    }
+
+    /// Evolves the universe `n` times
+    pub fn step_n(&mut self, n: usize) {
+        for _ in 0..n {
+            self.evolve();
+        }
+    }
+
+    /// Evolves the universe `steps` times and returns what `f` observed
+    /// after each step
+    ///
+    /// For example, `|u| u.count_alive()` records the population history.
+    #[cfg(feature = "alloc")]
+    pub fn run_and_collect<F, T>(&mut self, steps: usize, mut f: F) -> Vec<T>
+    where
+        F: FnMut(&Self) -> T,
+    {
+        let mut collected = Vec::with_capacity(steps);
+        for _ in 0..steps {
+            self.evolve();
+            collected.push(f(self));
+        }
+        collected
+    }
+
+    /// Evolves the universe until `condition` holds, for at most `max_steps`
+    /// generations
+    ///
+    /// The condition is checked before the first step and after every step,
+    /// so a universe that already satisfies it is not evolved at all.
+    pub fn step_until<F: Fn(&Self) -> bool>(
+        &mut self,
+        condition: F,
+        max_steps: usize,
+    ) -> StepResult {
+        for step in 0..=max_steps {
+            if condition(self) {
+                return StepResult::ConditionMet {
+                    at_generation: self.generation,
+                };
+            }
+            if step < max_steps {
+                self.evolve();
+            }
+        }
+        StepResult::MaxStepsReached
+    }
+
+    /// Evolves the universe until it becomes a still life or a period-2
+    /// oscillator, for at most `max_steps` generations
+    ///
+    /// Each generation is compared with the two before it, so a universe is
+    /// recognised one or two steps after it settles: the result reports the
+    /// generation it settled at, while the universe is left where the
+    /// repetition was seen.
+    pub fn run_to_fixpoint(&mut self, max_steps: usize) -> FixpointResult {
+        let mut previous = [None, None];
+        let mut current = self.state_grid();
+        for _ in 0..max_steps {
+            previous = [Some(current), previous[0]];
+            self.evolve();
+            current = self.state_grid();
+            if previous[0] == Some(current) {
+                return FixpointResult::StillLife {
+                    at_generation: self.generation - 1,
+                };
+            }
+            if previous[1] == Some(current) {
+                return FixpointResult::Period2Oscillator {
+                    at_generation: self.generation - 2,
+                };
+            }
+        }
+        FixpointResult::MaxStepsReached
+    }
+
+    /// Returns the smallest number of generations, up to `max_period`, after
+    /// which the cells return to their current states
+    ///
+    /// Still lifes have period 1. The universe is restored to its current
+    /// state, including its generation, before returning.
+    pub fn detect_period(&mut self, max_period: usize) -> Option<usize> {
+        let initial = self.clone();
+        let period = (1..=max_period).find(|_| {
+            self.evolve();
+            *self == initial
+        });
+        *self = initial;
+        period
+    }
+
+    /// Returns the period of the cycle the universe eventually enters, found
+    /// with Floyd's tortoise-and-hare algorithm within `max_steps` steps
+    ///
+    /// Unlike [`detect_period`](Self::detect_period) the universe need not be
+    /// on the cycle already: a pattern that settles into a still life after a
+    /// few generations has period 1. Only two extra copies of the universe are
+    /// kept, and the universe itself is left in its current state.
+    pub fn find_period_floyd(&mut self, max_steps: usize) -> Option<usize> {
+        let mut tortoise = self.clone();
+        let mut hare = self.clone();
+        for _ in 0..max_steps {
+            tortoise.evolve();
+            hare.evolve();
+            hare.evolve();
+            if tortoise == hare {
+                // Both are on the cycle, so walk once around it
+                let mut period = 1;
+                hare.evolve();
+                while hare != tortoise {
+                    hare.evolve();
+                    period += 1;
+                }
+                return Some(period);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_n_matches_evolve() {
+        let mut stepped = Universe::<8, 8>::new();
+        stepped.seed_glider(0, 0).unwrap();
+        let mut evolved = stepped.clone();
+        stepped.step_n(4);
+        for _ in 0..4 {
+            evolved.evolve();
+        }
+        assert_eq!(stepped, evolved);
+        assert_eq!(stepped.generation(), 4);
+    }
+
+    #[test]
+    fn test_step_until_max_steps() {
+        let mut blinker = Universe::<5, 5>::new();
+        blinker.seed_blinker(2, 1).unwrap();
+        assert_eq!(
+            blinker.step_until(|u| u.is_empty(), 100),
+            StepResult::MaxStepsReached
+        );
+        assert_eq!(blinker.generation(), 100);
+    }
+
+    #[test]
+    fn test_step_until_already_met() {
+        let mut block = Universe::<6, 6>::new();
+        block.seed_block(2, 2).unwrap();
+        assert_eq!(
+            block.step_until(|u| u.is_stable(), 100),
+            StepResult::ConditionMet { at_generation: 0 }
+        );
+        assert_eq!(block.generation(), 0);
+    }
+
+    #[test]
+    fn test_step_until_condition_met() {
+        let mut universe = Universe::<8, 8>::new();
+        universe.seed_glider(0, 0).unwrap();
+        let mut moved = Universe::<8, 8>::new();
+        moved.seed_glider(1, 1).unwrap();
+        assert_eq!(
+            universe.step_until(|u| *u == moved, 10),
+            StepResult::ConditionMet { at_generation: 4 }
+        );
+    }
+
+    #[test]
+    fn test_detect_period_oscillators() {
+        let mut blinker = Universe::<5, 5>::new();
+        blinker.seed_blinker(2, 1).unwrap();
+        assert_eq!(blinker.detect_period(10), Some(2));
+
+        let mut block = Universe::<6, 6>::new();
+        block.seed_block(2, 2).unwrap();
+        assert_eq!(block.detect_period(10), Some(1));
+    }
+
+    #[test]
+    fn test_detect_period_wrapping_glider() {
+        let mut glider = Universe::<10, 10>::new();
+        glider.seed_glider(0, 0).unwrap();
+        assert_eq!(glider.detect_period(39), None);
+        assert_eq!(glider.detect_period(100), Some(40));
+    }
+
+    #[test]
+    fn test_detect_period_restores_state() {
+        let mut universe = Universe::<20, 20>::new();
+        universe
+            .set_cells_alive(&[(8, 9), (8, 10), (9, 8), (9, 9), (10, 9)])
+            .unwrap();
+        universe.step_n(3);
+        let before = universe.clone();
+        assert_eq!(universe.detect_period(20), None);
+        assert_eq!(universe, before);
+        assert_eq!(universe.generation(), 3);
+    }
+
+    #[test]
+    fn test_find_period_floyd() {
+        let mut blinker = Universe::<5, 5>::new();
+        blinker.seed_blinker(2, 1).unwrap();
+        let before = blinker.clone();
+        assert_eq!(blinker.find_period_floyd(10), Some(2));
+        assert_eq!(blinker, before);
+        assert_eq!(blinker.generation(), 0);
+
+        let mut block = Universe::<6, 6>::new();
+        block.seed_block(2, 2).unwrap();
+        assert_eq!(block.find_period_floyd(10), Some(1));
+
+        let mut glider = Universe::<10, 10>::new();
+        glider.seed_glider(0, 0).unwrap();
+        assert_eq!(glider.find_period_floyd(39), None);
+        assert_eq!(glider.find_period_floyd(100), Some(40));
+    }
+
+    #[test]
+    fn test_find_period_floyd_after_transient() {
+        // Three cells in an L become a block after one generation
+        let mut universe = Universe::<6, 6>::new();
+        universe.set_cells_alive(&[(2, 2), (2, 3), (3, 2)]).unwrap();
+        assert_eq!(universe.detect_period(10), None);
+        assert_eq!(universe.find_period_floyd(10), Some(1));
+    }
+
+    #[test]
+    fn test_run_to_fixpoint() {
+        let mut block = Universe::<6, 6>::new();
+        block.seed_block(2, 2).unwrap();
+        assert_eq!(
+            block.run_to_fixpoint(10),
+            FixpointResult::StillLife { at_generation: 0 }
+        );
+
+        let mut blinker = Universe::<5, 5>::new();
+        blinker.seed_blinker(2, 1).unwrap();
+        assert_eq!(
+            blinker.run_to_fixpoint(10),
+            FixpointResult::Period2Oscillator { at_generation: 0 }
+        );
+        assert_eq!(blinker.generation(), 2);
+
+        // Three cells in an L become a block after one generation
+        let mut universe = Universe::<6, 6>::new();
+        universe.set_cells_alive(&[(2, 2), (2, 3), (3, 2)]).unwrap();
+        assert_eq!(
+            universe.run_to_fixpoint(10),
+            FixpointResult::StillLife { at_generation: 1 }
+        );
+    }
+
+    #[test]
+    fn test_r_pentomino_does_not_settle_quickly() {
+        let mut universe = Universe::<64, 64>::new();
+        universe
+            .set_cells_alive(&[(31, 32), (31, 33), (32, 31), (32, 32), (33, 32)])
+            .unwrap();
+        assert_eq!(
+            universe.run_to_fixpoint(100),
+            FixpointResult::MaxStepsReached
+        );
+        assert_eq!(universe.generation(), 100);
+        assert_eq!(universe.run_to_fixpoint(0), FixpointResult::MaxStepsReached);
+    }
+
+    #[test]
+    fn test_batch_evolve_iter() {
+        let mut universe = Universe::<8, 8>::new();
+        universe.seed_glider(1, 1).unwrap();
+        let mut manual = universe.clone();
+
+        assert_eq!(universe.batch_evolve_iter(0).next(), None);
+        assert_eq!(universe.generation(), 0);
+
+        let mut iter = universe.batch_evolve_iter(4);
+        assert_eq!(iter.len(), 4);
+        let mut yielded = 0;
+        for states in iter.by_ref() {
+            manual.evolve();
+            assert_eq!(states, manual.state_grid());
+            yielded += 1;
+        }
+        assert_eq!(yielded, 4);
+        assert_eq!(iter.next(), None);
+        assert_eq!(universe.generation(), 4);
+        assert_eq!(universe, manual);
+    }
+
+    #[test]
+    fn test_batch_evolve_iter_is_lazy() {
+        let mut universe = Universe::<5, 5>::new();
+        universe.seed_blinker(2, 1).unwrap();
+        assert_eq!(universe.batch_evolve_iter(10).take(3).count(), 3);
+        assert_eq!(universe.generation(), 3);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_run_and_collect() {
+        let mut universe = Universe::<5, 5>::new();
+        universe.seed_blinker(2, 1).unwrap();
+        let populations = universe.run_and_collect(10, |u| u.count_alive());
+        assert_eq!(populations, [3; 10]);
+        assert_eq!(
+            universe.run_and_collect(5, |u| u.generation()),
+            [11, 12, 13, 14, 15]
+        );
+        assert!(universe.run_and_collect(0, |u| u.generation()).is_empty());
+
+        let mut fresh = Universe::<5, 5>::new();
+        assert_eq!(
+            fresh.run_and_collect(5, |u| u.generation()),
+            [1, 2, 3, 4, 5]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_run_and_collect_checksums_reveal_cycle() {
+        let mut universe = Universe::<5, 5>::new();
+        universe.seed_blinker(2, 1).unwrap();
+        let checksums = universe.run_and_collect(6, |u| u.checksum());
+        assert_ne!(checksums[0], checksums[1]);
+        for (i, checksum) in checksums.iter().enumerate().skip(2) {
+            assert_eq!(*checksum, checksums[i - 2]);
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{Neighborhood, Universe};
+
+/// A set of the eight symmetries of the square, as a bitmask
+///
+/// Returned by [`Universe::symmetry_group`]. The identity is always present.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DihedralGroup(u8);
+
+impl DihedralGroup {
+    pub const IDENTITY: DihedralGroup = DihedralGroup(1 << 0);
+    /// A quarter turn clockwise, as by `rotate_90()`
+    pub const ROTATE_90: DihedralGroup = DihedralGroup(1 << 1);
+    pub const ROTATE_180: DihedralGroup = DihedralGroup(1 << 2);
+    pub const ROTATE_270: DihedralGroup = DihedralGroup(1 << 3);
+    /// Mirroring left to right, as by `flip_horizontal()`
+    pub const FLIP_HORIZONTAL: DihedralGroup = DihedralGroup(1 << 4);
+    /// Mirroring top to bottom, as by `flip_vertical()`
+    pub const FLIP_VERTICAL: DihedralGroup = DihedralGroup(1 << 5);
+    /// Mirroring along the main diagonal, as by `transpose()`
+    pub const TRANSPOSE: DihedralGroup = DihedralGroup(1 << 6);
+    /// Mirroring along the other diagonal
+    pub const ANTI_TRANSPOSE: DihedralGroup = DihedralGroup(1 << 7);
+    /// All eight symmetries
+    pub const ALL: DihedralGroup = DihedralGroup(u8::MAX);
+
+    /// Returns the raw bitmask
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns true if every symmetry in `other` is also in `self`
+    pub const fn contains(self, other: DihedralGroup) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the number of symmetries in the set
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns true if the set holds no symmetry
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl core::ops::BitOr for DihedralGroup {
+    type Output = DihedralGroup;
+
+    fn bitor(self, other: DihedralGroup) -> DihedralGroup {
+        DihedralGroup(self.0 | other.0)
+    }
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Returns true if the cells are unchanged by `flip_horizontal()`
+    pub fn has_left_right_symmetry(&self) -> bool {
+        self.is_invariant(|row, column| (row, W - 1 - column))
+    }
+
+    /// Returns true if the cells are unchanged by `flip_vertical()`
+    pub fn has_top_bottom_symmetry(&self) -> bool {
+        self.is_invariant(|row, column| (H - 1 - row, column))
+    }
+
+    /// Returns true if the cells are unchanged by `rotate_180()`
+    pub fn has_rotational_symmetry_180(&self) -> bool {
+        self.is_invariant(|row, column| (H - 1 - row, W - 1 - column))
+    }
+
+    /// Returns true if the cells are unchanged by every rotation
+    ///
+    /// This and the diagonal symmetries are only possible on a square grid.
+    pub fn has_four_fold_symmetry(&self) -> bool {
+        W == H && self.is_invariant(|row, column| (column, H - 1 - row))
+    }
+
+    /// Returns the symmetries of the square that leave the cells unchanged
+    pub fn symmetry_group(&self) -> DihedralGroup {
+        let square = W == H;
+        let checks = [
+            (DihedralGroup::IDENTITY, true),
+            (DihedralGroup::ROTATE_90, self.has_four_fold_symmetry()),
+            (
+                DihedralGroup::ROTATE_180,
+                self.has_rotational_symmetry_180(),
+            ),
+            (DihedralGroup::ROTATE_270, self.has_four_fold_symmetry()),
+            (
+                DihedralGroup::FLIP_HORIZONTAL,
+                self.has_left_right_symmetry(),
+            ),
+            (DihedralGroup::FLIP_VERTICAL, self.has_top_bottom_symmetry()),
+            (
+                DihedralGroup::TRANSPOSE,
+                square && self.is_invariant(|row, column| (column, row)),
+            ),
+            (
+                DihedralGroup::ANTI_TRANSPOSE,
+                square && self.is_invariant(|row, column| (W - 1 - column, H - 1 - row)),
+            ),
+        ];
+        checks
+            .into_iter()
+            .filter(|&(_, holds)| holds)
+            .fold(DihedralGroup::default(), |group, (symmetry, _)| {
+                group | symmetry
+            })
+    }
+
+    /// Returns true if every cell has the same state as the cell `map` sends
+    /// it to, which must lie inside the grid
+    fn is_invariant(&self, map: impl Fn(usize, usize) -> (usize, usize)) -> bool {
+        (0..H).all(|row| {
+            (0..W).all(|column| {
+                let (mapped_row, mapped_col) = map(row, column);
+                self.grid[row][column].is_alive() == self.grid[mapped_row][mapped_col].is_alive()
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vertical_bar() {
+        let mut bar = Universe::<5, 5>::new();
+        bar.set_cells_alive(&[(0, 2), (1, 2), (2, 2)]).unwrap();
+        assert!(bar.has_left_right_symmetry());
+        assert!(!bar.has_top_bottom_symmetry());
+        assert!(!bar.has_rotational_symmetry_180());
+        assert_eq!(
+            bar.symmetry_group(),
+            DihedralGroup::IDENTITY | DihedralGroup::FLIP_HORIZONTAL
+        );
+    }
+
+    #[test]
+    fn test_block_and_empty_have_every_symmetry() {
+        let mut block = Universe::<6, 6>::new();
+        block.seed_block(2, 2).unwrap();
+        assert!(block.has_left_right_symmetry());
+        assert!(block.has_top_bottom_symmetry());
+        assert!(block.has_rotational_symmetry_180());
+        assert!(block.has_four_fold_symmetry());
+        assert_eq!(block.symmetry_group(), DihedralGroup::ALL);
+        assert_eq!(Universe::<4, 4>::new().symmetry_group(), DihedralGroup::ALL);
+    }
+
+    #[test]
+    fn test_glider_has_no_symmetry() {
+        let mut glider = Universe::<5, 5>::new();
+        glider.seed_glider(1, 1).unwrap();
+        assert!(!glider.has_left_right_symmetry());
+        assert!(!glider.has_top_bottom_symmetry());
+        assert!(!glider.has_rotational_symmetry_180());
+        assert!(!glider.has_four_fold_symmetry());
+        assert_eq!(glider.symmetry_group(), DihedralGroup::IDENTITY);
+    }
+
+    #[test]
+    fn test_rectangle_symmetries() {
+        let mut blinker = Universe::<5, 3>::new();
+        blinker.seed_blinker(1, 1).unwrap();
+        let group = blinker.symmetry_group();
+        assert!(group.contains(DihedralGroup::ROTATE_180));
+        assert!(!group.contains(DihedralGroup::ROTATE_90));
+        assert_eq!(group.len(), 4);
+    }
+}
@@ -0,0 +1,406 @@
+use crate::{Cell, Neighborhood, State, Universe};
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Returns the universe rotated a quarter turn clockwise
+    ///
+    /// The cell at `(r, c)` moves to `(c, H - 1 - r)`, so the width and the
+    /// height swap. The rule is kept and the boundary turns with the grid, so
+    /// an axis that wrapped still does; the generation restarts at 0.
+    pub fn rotate_90(&self) -> Universe<H, W, N> {
+        self.transformed(|row, column| (column, H - 1 - row))
+            .with_transposed_boundary()
+    }
+
+    /// Returns the universe rotated a half turn
+    ///
+    /// The cell at `(r, c)` moves to `(H - 1 - r, W - 1 - c)`.
+    pub fn rotate_180(&self) -> Universe<W, H, N> {
+        self.transformed(|row, column| (H - 1 - row, W - 1 - column))
+    }
+
+    /// Returns the universe rotated a quarter turn counterclockwise
+    ///
+    /// The cell at `(r, c)` moves to `(W - 1 - c, r)`, so the width and the
+    /// height swap.
+    pub fn rotate_270(&self) -> Universe<H, W, N> {
+        self.transformed(|row, column| (W - 1 - column, row))
+            .with_transposed_boundary()
+    }
+
+    /// Returns the universe mirrored left to right
+    ///
+    /// The cell at `(r, c)` moves to `(r, W - 1 - c)`.
+    pub fn flip_horizontal(&self) -> Universe<W, H, N> {
+        self.transformed(|row, column| (row, W - 1 - column))
+    }
+
+    /// Returns the universe mirrored top to bottom
+    ///
+    /// The cell at `(r, c)` moves to `(H - 1 - r, c)`.
+    pub fn flip_vertical(&self) -> Universe<W, H, N> {
+        self.transformed(|row, column| (H - 1 - row, column))
+    }
+
+    /// Returns the universe mirrored along its main diagonal
+    ///
+    /// The cell at `(r, c)` moves to `(c, r)`, so the width and the height
+    /// swap. With the rotations and flips this covers all eight symmetries of
+    /// the square.
+    pub fn transpose(&self) -> Universe<H, W, N> {
+        self.transformed(|row, column| (column, row))
+            .with_transposed_boundary()
+    }
+
+    /// Returns the universe with every alive cell moved `delta_row` rows down
+    /// and `delta_col` columns right
+    ///
+    /// Cells moved past an edge come back on the other side if that axis
+    /// wraps around, and are dropped otherwise.
+    pub fn translate(&self, delta_row: i64, delta_col: i64) -> Universe<W, H, N> {
+        let (wrap_rows, wrap_cols) = self.boundary.wraps();
+        let shift = |index: usize, delta: i64, len: usize, wraps: bool| {
+            if wraps {
+                let delta = delta.rem_euclid(len as i64);
+                Some((index as i64 + delta).rem_euclid(len as i64) as usize)
+            } else {
+                let target = (index as i64).checked_add(delta)?;
+                (0..len as i64).contains(&target).then_some(target as usize)
+            }
+        };
+        let mut universe = self.empty_like();
+        self.for_each_alive(|row, column| {
+            if let (Some(row), Some(column)) = (
+                shift(row, delta_row, H, wrap_rows),
+                shift(column, delta_col, W, wrap_cols),
+            ) {
+                universe.grid[row][column].set_state(State::Alive);
+            }
+        });
+        universe
+    }
+
+    /// Moves every alive cell as [`translate`](Self::translate) does, keeping
+    /// the generation
+    pub fn translate_in_place(&mut self, delta_row: i64, delta_col: i64) {
+        self.grid = self.translate(delta_row, delta_col).grid;
+        self.evolved = false;
+    }
+
+    /// Returns the pattern shifted so its bounding box starts at `(0, 0)`,
+    /// together with the `(row, column)` offset it was shifted from
+    ///
+    /// Two universes holding the same pattern at different positions have
+    /// equal canonical forms. An empty universe is returned unchanged with an
+    /// offset of `(0, 0)`.
+    pub fn to_canonical_form(&self) -> (Universe<W, H, N>, (usize, usize)) {
+        match self.bounding_box() {
+            None => (self.empty_like(), (0, 0)),
+            Some(bounds) => (
+                self.transformed(|row, column| (row - bounds.min_row, column - bounds.min_col)),
+                (bounds.min_row, bounds.min_col),
+            ),
+        }
+    }
+
+    /// Returns true if `other` holds the same pattern as this universe, up to
+    /// translation
+    pub fn is_same_pattern_as(&self, other: &Universe<W, H, N>) -> bool {
+        self.to_canonical_form().0 == other.to_canonical_form().0
+    }
+
+    /// Returns a universe whose cells take the states `f(row, column, cell)`
+    /// gives for the cells of this one
+    ///
+    /// The new cells have no cached neighbor counts or ages.
+    pub fn map_cells<F: Fn(usize, usize, Cell) -> State>(&self, f: F) -> Universe<W, H, N> {
+        let mut universe = self.empty_like();
+        for (row, cells) in self.grid.iter().enumerate() {
+            for (column, &cell) in cells.iter().enumerate() {
+                universe.grid[row][column] = Cell::new().with_state(f(row, column, cell));
+            }
+        }
+        universe
+    }
+
+    /// Copies every alive cell to the position `map` gives for it in a new
+    /// universe with the same rule and boundary
+    fn transformed<const W2: usize, const H2: usize>(
+        &self,
+        map: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Universe<W2, H2, N> {
+        let mut universe = self.empty_like();
+        for (row, cells) in self.grid.iter().enumerate() {
+            for (column, cell) in cells.iter().enumerate() {
+                if cell.is_alive() {
+                    let (new_row, new_column) = map(row, column);
+                    universe.grid[new_row][new_column].set_state(State::Alive);
+                }
+            }
+        }
+        universe
+    }
+
+    /// Swaps the axes of the boundary condition after a transform that swapped
+    /// the rows and the columns
+    fn with_transposed_boundary(mut self) -> Self {
+        self.boundary = self.boundary.transposed();
+        self
+    }
+
+    /// Returns an empty universe of any size with the same rule and boundary
+    pub(crate) fn empty_like<const W2: usize, const H2: usize>(&self) -> Universe<W2, H2, N> {
+        let mut universe = Universe::with_rule(self.rule);
+        universe.boundary = self.boundary;
+        universe
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BoundaryCondition;
+
+    const L_SHAPE: [[bool; 5]; 3] = [
+        [true, false, false, false, false],
+        [true, false, false, false, false],
+        [true, true, true, false, true],
+    ];
+
+    #[test]
+    fn test_four_quarter_turns_are_identity() {
+        let universe = Universe::<5, 3>::from(L_SHAPE);
+        let turned = universe.rotate_90().rotate_90().rotate_90().rotate_90();
+        assert_eq!(<[[bool; 5]; 3]>::from(turned), L_SHAPE);
+    }
+
+    #[test]
+    fn test_rotate_90_swaps_dimensions() {
+        let universe = Universe::<5, 3>::from(L_SHAPE);
+        let turned: Universe<3, 5> = universe.rotate_90();
+        assert_eq!(
+            <[[bool; 3]; 5]>::from(turned),
+            [
+                [true, true, true],
+                [true, false, false],
+                [true, false, false],
+                [false, false, false],
+                [true, false, false],
+            ]
+        );
+        assert_eq!(
+            <[[bool; 3]; 5]>::from(universe.rotate_270()),
+            <[[bool; 3]; 5]>::from(universe.rotate_180().rotate_90())
+        );
+    }
+
+    #[test]
+    fn test_rotate_180() {
+        let universe = Universe::<5, 3>::from(L_SHAPE);
+        assert_eq!(
+            <[[bool; 5]; 3]>::from(universe.rotate_180()),
+            [
+                [true, false, true, true, true],
+                [false, false, false, false, true],
+                [false, false, false, false, true],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rotate_square() {
+        let mut universe = Universe::<6, 6>::new();
+        universe.seed_glider(0, 0).unwrap();
+        let turned: Universe<6, 6> = universe.rotate_90();
+        assert_eq!(turned.count_alive(), 5);
+        assert_eq!(turned.rotate_270(), universe);
+    }
+
+    #[test]
+    fn test_flip_twice_is_identity() {
+        let universe = Universe::<5, 3>::from(L_SHAPE);
+        assert_eq!(universe.flip_horizontal().flip_horizontal(), universe);
+        assert_eq!(universe.flip_vertical().flip_vertical(), universe);
+        assert_eq!(
+            <[[bool; 5]; 3]>::from(universe.flip_horizontal().flip_vertical()),
+            <[[bool; 5]; 3]>::from(universe.rotate_180())
+        );
+    }
+
+    #[test]
+    fn test_flip_horizontal_keeps_centered_bar() {
+        let mut bar = Universe::<5, 5>::new();
+        bar.set_cells_alive(&[(1, 2), (2, 2), (3, 2)]).unwrap();
+        assert_eq!(bar.flip_horizontal(), bar);
+        assert_ne!(bar.flip_vertical().transpose(), bar);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let universe = Universe::<5, 3>::from(L_SHAPE);
+        let transposed: Universe<3, 5> = universe.transpose();
+        assert!(transposed.is_alive(4, 2));
+        assert!(!transposed.is_alive(3, 2));
+        assert_eq!(<[[bool; 5]; 3]>::from(transposed.transpose()), L_SHAPE);
+    }
+
+    #[test]
+    fn test_axis_swaps_turn_the_cylinder() {
+        let mut tube = Universe::<12, 10>::new();
+        tube.set_wrapping(true, false);
+        let turned = BoundaryCondition::Cylindrical {
+            wrap_rows: false,
+            wrap_cols: true,
+        };
+        assert_eq!(tube.rotate_90().boundary(), turned);
+        assert_eq!(tube.rotate_270().boundary(), turned);
+        assert_eq!(tube.transpose().boundary(), turned);
+        assert_eq!(tube.rotate_180().boundary(), tube.boundary());
+
+        // After the turn the glider crosses the right edge, not the bottom one
+        let mut rotated: Universe<10, 12> = tube.rotate_90();
+        rotated.seed_glider(0, 6).unwrap();
+        rotated.step_n(20);
+        let mut expected = Universe::<10, 12>::new();
+        expected
+            .set_cells_alive(&[(5, 2), (6, 3), (7, 1), (7, 2), (7, 3)])
+            .unwrap();
+        assert_eq!(rotated, expected);
+
+        let fixed = Universe::<12, 10>::with_boundary(BoundaryCondition::FixedDead);
+        assert_eq!(fixed.transpose().boundary(), BoundaryCondition::FixedDead);
+    }
+
+    #[test]
+    fn test_rotate_then_flip_is_transpose() {
+        let universe = Universe::<5, 3>::from(L_SHAPE);
+        assert_eq!(
+            <[[bool; 3]; 5]>::from(universe.rotate_90().flip_horizontal()),
+            <[[bool; 3]; 5]>::from(universe.transpose())
+        );
+    }
+
+    #[test]
+    fn test_map_cells_inverts() {
+        let universe = Universe::<5, 3>::from(L_SHAPE);
+        let inverted = universe.map_cells(|_, _, cell| {
+            if cell.is_alive() {
+                State::Dead
+            } else {
+                State::Alive
+            }
+        });
+        let mut full = Universe::<5, 3>::new();
+        full.fill(State::Alive);
+        assert_eq!(inverted, universe.xor_with(&full));
+    }
+
+    #[test]
+    fn test_map_cells_by_position() {
+        let mut universe = Universe::<8, 8>::new();
+        universe.seed_blinker(1, 0).unwrap();
+        universe.seed_block(0, 4).unwrap();
+        universe.evolve();
+        let grid = universe.grid();
+        assert!(grid.iter().flatten().any(|cell| cell.live_neighbors() > 0));
+        let mapped = universe.map_cells(|row, column, _| {
+            if row == 0 {
+                State::Dead
+            } else {
+                grid[row][column].state()
+            }
+        });
+        let in_first_row = grid[0].iter().filter(|cell| cell.is_alive()).count();
+        assert!(in_first_row > 0);
+        assert_eq!(mapped.count_alive(), universe.count_alive() - in_first_row);
+        assert!((0..8).all(|column| !mapped.is_alive(0, column)));
+        assert!((1..8)
+            .all(|row| (0..8)
+                .all(|column| mapped.is_alive(row, column) == grid[row][column].is_alive())));
+        assert!(mapped
+            .grid()
+            .iter()
+            .flatten()
+            .all(|cell| cell.live_neighbors() == 0 && cell.age() == 0));
+    }
+
+    #[test]
+    fn test_translate_glider() {
+        let mut universe = Universe::<10, 10>::new();
+        universe.seed_glider(2, 2).unwrap();
+        let mut expected = Universe::<10, 10>::new();
+        expected.seed_glider(3, 3).unwrap();
+        assert_eq!(universe.translate(1, 1), expected);
+        assert_eq!(universe.translate(10, 10), universe);
+        assert_eq!(universe.translate(-20, 30), universe);
+    }
+
+    #[test]
+    fn test_translate_drops_cells_under_fixed_dead() {
+        let mut universe = Universe::<5, 5>::with_boundary(BoundaryCondition::FixedDead);
+        universe.seed_block(0, 0).unwrap();
+        let moved = universe.translate(-1, -1);
+        assert!(moved.alive_cells().eq([(0, 0)]));
+
+        let mut tube = Universe::<5, 5>::new();
+        tube.set_wrapping(false, true);
+        tube.seed_block(0, 0).unwrap();
+        let moved = tube.translate(-1, -1);
+        assert!(moved.alive_cells().eq([(0, 0), (0, 4)]));
+    }
+
+    #[test]
+    fn test_translate_by_extreme_deltas() {
+        let mut universe = Universe::<5, 4>::new();
+        universe.set_cell(1, 2, State::Alive);
+        // i64::MAX is 3 mod 4 rows, and i64::MIN is 2 mod 5 columns
+        let moved = universe.translate(i64::MAX, i64::MIN);
+        assert!(moved.alive_cells().eq([(0, 4)]));
+
+        universe.boundary = BoundaryCondition::FixedDead;
+        assert!(universe.translate(i64::MAX, 0).is_empty());
+        assert!(universe.translate(0, i64::MIN).is_empty());
+    }
+
+    #[test]
+    fn test_translate_in_place_round_trip() {
+        let mut universe = Universe::<8, 6>::new();
+        universe.seed_glider(0, 5).unwrap();
+        universe.evolve();
+        let original = universe.clone();
+        universe.translate_in_place(4, -7);
+        assert_ne!(universe, original);
+        universe.translate_in_place(-4, 7);
+        assert_eq!(universe, original);
+        assert_eq!(universe.generation(), 1);
+    }
+
+    #[test]
+    fn test_canonical_form_ignores_position() {
+        let mut moved = Universe::<10, 10>::new();
+        moved.seed_glider(3, 5).unwrap();
+        let mut origin = Universe::<10, 10>::new();
+        origin.seed_glider(0, 0).unwrap();
+
+        let (canonical, offset) = moved.to_canonical_form();
+        assert_eq!(canonical, origin.to_canonical_form().0);
+        assert_eq!(canonical.translate(offset.0 as i64, offset.1 as i64), moved);
+        assert!(moved.is_same_pattern_as(&origin));
+    }
+
+    #[test]
+    fn test_different_patterns_are_not_the_same() {
+        let mut glider = Universe::<10, 10>::new();
+        glider.seed_glider(3, 5).unwrap();
+        let mut block = Universe::<10, 10>::new();
+        block.seed_block(3, 5).unwrap();
+        assert_ne!(glider.to_canonical_form().0, block.to_canonical_form().0);
+        assert!(!glider.is_same_pattern_as(&block));
+    }
+
+    #[test]
+    fn test_canonical_form_of_empty_universe() {
+        let (canonical, offset) = Universe::<6, 4>::new().to_canonical_form();
+        assert_eq!(canonical.count_alive(), 0);
+        assert_eq!(offset, (0, 0));
+    }
+}
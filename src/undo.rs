@@ -0,0 +1,160 @@
+use alloc::collections::VecDeque;
+use core::fmt;
+
+use crate::{Neighborhood, Snapshot, Universe};
+
+/// The error returned by [`Universe::undo`] when no snapshot has been saved
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum UndoError {
+    /// There is no snapshot to restore
+    StackEmpty,
+}
+
+impl fmt::Display for UndoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UndoError::StackEmpty => write!(f, "there is no snapshot to undo to"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UndoError {}
+
+/// Saved snapshots, oldest first
+#[derive(Clone, Debug)]
+pub(crate) struct UndoStack<const W: usize, const H: usize> {
+    snapshots: VecDeque<Snapshot<W, H>>,
+    max_depth: usize,
+}
+
+impl<const W: usize, const H: usize> UndoStack<W, H> {
+    fn new() -> Self {
+        UndoStack {
+            snapshots: VecDeque::new(),
+            max_depth: usize::MAX,
+        }
+    }
+
+    /// Drops the oldest snapshots until at most `max_depth` remain
+    fn truncate(&mut self) {
+        while self.snapshots.len() > self.max_depth {
+            self.snapshots.pop_front();
+        }
+    }
+}
+
+impl<const W: usize, const H: usize, N: Neighborhood> Universe<W, H, N> {
+    /// Saves the cell states and the generation so [`undo`](Self::undo) can
+    /// return to them
+    ///
+    /// Once the maximum undo depth is reached, the oldest snapshot is evicted.
+    pub fn push_snapshot(&mut self) {
+        let snapshot = self.save_snapshot();
+        let stack = self.undo.get_or_insert_with(UndoStack::new);
+        stack.snapshots.push_back(snapshot);
+        stack.truncate();
+    }
+
+    /// Restores the most recently saved snapshot and discards it
+    pub fn undo(&mut self) -> Result<(), UndoError> {
+        let snapshot = self
+            .undo
+            .as_mut()
+            .and_then(|stack| stack.snapshots.pop_back())
+            .ok_or(UndoError::StackEmpty)?;
+        self.restore_snapshot(&snapshot);
+        Ok(())
+    }
+
+    /// Restores the oldest saved snapshot and discards every snapshot
+    ///
+    /// Does nothing if no snapshot has been saved.
+    pub fn undo_all(&mut self) {
+        let oldest = self.undo.as_mut().and_then(|stack| {
+            let oldest = stack.snapshots.pop_front();
+            stack.snapshots.clear();
+            oldest
+        });
+        if let Some(snapshot) = oldest {
+            self.restore_snapshot(&snapshot);
+        }
+    }
+
+    /// Limits the number of saved snapshots, evicting the oldest ones if there
+    /// are already more than `depth`
+    ///
+    /// The depth is unlimited by default.
+    pub fn set_max_undo_depth(&mut self, depth: usize) {
+        let stack = self.undo.get_or_insert_with(UndoStack::new);
+        stack.max_depth = depth;
+        stack.truncate();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glider() -> Universe<8, 8> {
+        let mut universe = Universe::new();
+        universe.seed_glider(0, 0).unwrap();
+        universe
+    }
+
+    #[test]
+    fn test_undo_returns_to_snapshots() {
+        let mut universe = glider();
+        let start = universe.clone();
+        universe.push_snapshot();
+        universe.step_n(2);
+        let step_two = universe.clone();
+        universe.push_snapshot();
+        universe.step_n(3);
+
+        universe.undo().unwrap();
+        assert_eq!(universe, step_two);
+        assert_eq!(universe.generation(), 2);
+        universe.undo().unwrap();
+        assert_eq!(universe, start);
+        assert_eq!(universe.generation(), 0);
+    }
+
+    #[test]
+    fn test_undo_empty_stack() {
+        let mut universe = glider();
+        assert_eq!(universe.undo(), Err(UndoError::StackEmpty));
+        universe.push_snapshot();
+        universe.undo().unwrap();
+        assert_eq!(universe.undo(), Err(UndoError::StackEmpty));
+    }
+
+    #[test]
+    fn test_undo_all() {
+        let mut universe = glider();
+        let start = universe.clone();
+        for _ in 0..3 {
+            universe.push_snapshot();
+            universe.evolve();
+        }
+        universe.undo_all();
+        assert_eq!(universe, start);
+        assert_eq!(universe.undo(), Err(UndoError::StackEmpty));
+    }
+
+    #[test]
+    fn test_max_undo_depth() {
+        let mut universe = glider();
+        universe.set_max_undo_depth(1);
+        universe.push_snapshot();
+        universe.evolve();
+        let latest = universe.clone();
+        universe.push_snapshot();
+        universe.step_n(3);
+
+        universe.undo().unwrap();
+        assert_eq!(universe, latest);
+        assert_eq!(universe.undo(), Err(UndoError::StackEmpty));
+    }
+}
@@ -0,0 +1,297 @@
+use crate::{BoundaryCondition, Cell, State};
+
+/// Which cells count as neighbors in a `Universe3D`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Neighborhood3D {
+    /// The 26 cells of the surrounding 3x3x3 cube
+    #[default]
+    Moore,
+    /// The 6 cells sharing a face
+    VonNeumann,
+}
+
+impl Neighborhood3D {
+    /// Returns true if the offset is a neighbor under this neighborhood
+    fn contains(self, delta: (isize, isize, isize)) -> bool {
+        let distance = delta.0.abs() + delta.1.abs() + delta.2.abs();
+        match self {
+            Neighborhood3D::Moore => distance > 0,
+            Neighborhood3D::VonNeumann => distance == 1,
+        }
+    }
+}
+
+/// A birth/survival rule for three-dimensional automata with up to 26 neighbors
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rule3D {
+    /// `birth[n]` is true if a dead cell with `n` live neighbors becomes alive
+    pub birth: [bool; 27],
+    /// `survival[n]` is true if a live cell with `n` live neighbors stays alive
+    pub survival: [bool; 27],
+}
+
+impl Rule3D {
+    /// Carter Bays' Life 5766: survives with 5 to 7 neighbors, born with 6
+    pub const BAYS_5766: Rule3D = Rule3D::from_counts(&[6], &[5, 6, 7]);
+
+    /// Creates a rule from the neighbor counts that cause birth and survival
+    ///
+    /// # Panics
+    ///
+    /// Panics if a count is greater than 26.
+    pub const fn from_counts(birth: &[u8], survival: &[u8]) -> Self {
+        let mut rule = Rule3D {
+            birth: [false; 27],
+            survival: [false; 27],
+        };
+        let mut i = 0;
+        while i < birth.len() {
+            rule.birth[birth[i] as usize] = true;
+            i += 1;
+        }
+        let mut i = 0;
+        while i < survival.len() {
+            rule.survival[survival[i] as usize] = true;
+            i += 1;
+        }
+        rule
+    }
+
+    /// Returns the next state of a cell with `live_neighbors` live neighbors
+    pub fn next_state(&self, state: State, live_neighbors: u8) -> State {
+        let table = match state {
+            State::Alive => &self.survival,
+            State::Dead => &self.birth,
+        };
+        match table.get(live_neighbors as usize) {
+            Some(true) => State::Alive,
+            _ => State::Dead,
+        }
+    }
+}
+
+impl Default for Rule3D {
+    fn default() -> Self {
+        Rule3D::BAYS_5766
+    }
+}
+
+/// A three-dimensional universe wrapping around in every direction
+///
+/// Cells are addressed by `(depth, row, column)`.
+#[derive(Clone)]
+pub struct Universe3D<const W: usize, const H: usize, const D: usize> {
+    grid: [[[Cell; W]; H]; D],
+    grid_back: [[[Cell; W]; H]; D],
+    rule: Rule3D,
+    neighborhood: Neighborhood3D,
+}
+
+impl<const W: usize, const H: usize, const D: usize> Universe3D<W, H, D> {
+    /// Creates an empty universe evolving by Life 5766 with the Moore neighborhood
+    pub const fn new() -> Self {
+        Self::with_rule(Rule3D::BAYS_5766, Neighborhood3D::Moore)
+    }
+
+    /// Creates an empty universe with the given rule and neighborhood
+    pub const fn with_rule(rule: Rule3D, neighborhood: Neighborhood3D) -> Self {
+        Universe3D {
+            grid: [[[Cell::new(); W]; H]; D],
+            grid_back: [[[Cell::new(); W]; H]; D],
+            rule,
+            neighborhood,
+        }
+    }
+
+    /// Returns the rule the universe evolves by
+    pub fn rule(&self) -> Rule3D {
+        self.rule
+    }
+
+    /// Returns which cells count as neighbors
+    pub fn neighborhood(&self) -> Neighborhood3D {
+        self.neighborhood
+    }
+
+    /// Sets the state of the cell
+    pub fn set_cell(&mut self, depth: usize, row: usize, column: usize, state: State) {
+        Self::check_bounds(depth, row, column);
+        self.grid[depth][row][column].set_state(state);
+    }
+
+    /// Returns true if the cell at the given position is alive
+    pub fn is_alive(&self, depth: usize, row: usize, column: usize) -> bool {
+        Self::check_bounds(depth, row, column);
+        self.grid[depth][row][column].is_alive()
+    }
+
+    /// Returns the number of alive cells
+    pub fn count_alive(&self) -> usize {
+        self.grid
+            .iter()
+            .flatten()
+            .flatten()
+            .filter(|cell| cell.is_alive())
+            .count()
+    }
+
+    /// Evolves the universe
+    pub fn evolve(&mut self) {
+        for depth in 0..D {
+            for row in 0..H {
+                for column in 0..W {
+                    let mut cell = self.grid[depth][row][column];
+                    cell.set_live_neighbors(self.live_neighbor_count(depth, row, column));
+                    cell.set_state(self.rule.next_state(cell.state(), cell.live_neighbors()));
+                    self.grid_back[depth][row][column] = cell;
+                }
+            }
+        }
+
+        core::mem::swap(&mut self.grid, &mut self.grid_back);
+    }
+
+    fn check_bounds(depth: usize, row: usize, column: usize) {
+        assert!(
+            depth < D && row < H && column < W,
+            "cell ({}, {}, {}) is out of bounds for a {}x{}x{} universe",
+            depth,
+            row,
+            column,
+            W,
+            H,
+            D
+        );
+    }
+
+    fn live_neighbor_count(&self, depth: usize, row: usize, column: usize) -> u8 {
+        let wrap = BoundaryCondition::Toroidal;
+        let mut count = 0;
+        for delta_depth in -1..=1 {
+            for delta_row in -1..=1 {
+                for delta_col in -1..=1 {
+                    if !self
+                        .neighborhood
+                        .contains((delta_depth, delta_row, delta_col))
+                    {
+                        continue;
+                    }
+                    let neighbor = (
+                        wrap.row_offset(depth, delta_depth, D),
+                        wrap.row_offset(row, delta_row, H),
+                        wrap.col_offset(column, delta_col, W),
+                    );
+                    if let (Some(d), Some(r), Some(c)) = neighbor {
+                        count += self.grid[d][r][c].state() as u8;
+                    }
+                }
+            }
+        }
+        count
+    }
+}
+
+impl<const W: usize, const H: usize, const D: usize> Default for Universe3D<W, H, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Carter Bays' glider for Life 5766, two identical layers moving one
+    /// cell up and left every four generations
+    const GLIDER_LAYER: [(usize, usize); 5] = [(0, 0), (1, 0), (2, 0), (0, 1), (1, 2)];
+
+    fn seed_glider<const W: usize, const H: usize, const D: usize>(
+        universe: &mut Universe3D<W, H, D>,
+        depth: usize,
+        row: usize,
+        column: usize,
+    ) {
+        for layer in depth..depth + 2 {
+            for &(r, c) in GLIDER_LAYER.iter() {
+                universe.set_cell(layer, row + r, column + c, State::Alive);
+            }
+        }
+    }
+
+    fn alive<const W: usize, const H: usize, const D: usize>(
+        universe: &Universe3D<W, H, D>,
+    ) -> [[[bool; W]; H]; D] {
+        universe
+            .grid
+            .map(|plane| plane.map(|row| row.map(|cell| cell.is_alive())))
+    }
+
+    #[test]
+    fn test_rule() {
+        let rule = Rule3D::BAYS_5766;
+        assert_eq!(rule.next_state(State::Dead, 6), State::Alive);
+        assert_eq!(rule.next_state(State::Dead, 5), State::Dead);
+        assert_eq!(rule.next_state(State::Alive, 5), State::Alive);
+        assert_eq!(rule.next_state(State::Alive, 8), State::Dead);
+        assert_eq!(rule.next_state(State::Alive, 30), State::Dead);
+        assert_eq!(Rule3D::default(), rule);
+    }
+
+    #[test]
+    fn test_interior_neighbors() {
+        let mut moore = Universe3D::<5, 5, 5>::new();
+        let mut von_neumann =
+            Universe3D::<5, 5, 5>::with_rule(Rule3D::BAYS_5766, Neighborhood3D::VonNeumann);
+        for depth in 1..4 {
+            for row in 1..4 {
+                for column in 1..4 {
+                    moore.set_cell(depth, row, column, State::Alive);
+                    von_neumann.set_cell(depth, row, column, State::Alive);
+                }
+            }
+        }
+        assert_eq!(moore.live_neighbor_count(2, 2, 2), 26);
+        assert_eq!(von_neumann.live_neighbor_count(2, 2, 2), 6);
+        assert_eq!(moore.live_neighbor_count(0, 0, 0), 1);
+        assert_eq!(moore.live_neighbor_count(1, 1, 1), 7);
+    }
+
+    #[test]
+    fn test_corner_neighbors_wrap() {
+        let mut universe = Universe3D::<4, 4, 4>::new();
+        universe.set_cell(3, 3, 3, State::Alive);
+        universe.set_cell(0, 3, 0, State::Alive);
+        universe.set_cell(3, 0, 1, State::Alive);
+        universe.set_cell(2, 0, 0, State::Alive);
+        assert_eq!(universe.live_neighbor_count(0, 0, 0), 3);
+
+        let mut von_neumann =
+            Universe3D::<4, 4, 4>::with_rule(Rule3D::BAYS_5766, Neighborhood3D::VonNeumann);
+        von_neumann.set_cell(3, 0, 0, State::Alive);
+        von_neumann.set_cell(0, 3, 0, State::Alive);
+        von_neumann.set_cell(0, 0, 3, State::Alive);
+        von_neumann.set_cell(3, 3, 3, State::Alive);
+        assert_eq!(von_neumann.live_neighbor_count(0, 0, 0), 3);
+    }
+
+    #[test]
+    fn test_glider_5766() {
+        let mut universe = Universe3D::<8, 8, 8>::new();
+        seed_glider(&mut universe, 3, 4, 4);
+        let start = alive(&universe);
+
+        for _ in 0..4 {
+            universe.evolve();
+        }
+        let mut expected = Universe3D::<8, 8, 8>::new();
+        seed_glider(&mut expected, 3, 3, 3);
+        assert_eq!(alive(&universe), alive(&expected));
+
+        // Another 28 generations take it once around the torus
+        for _ in 0..28 {
+            universe.evolve();
+        }
+        assert_eq!(universe.count_alive(), 10);
+        assert_eq!(alive(&universe), start);
+    }
+}
@@ -0,0 +1,90 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{DynamicUniverse, State};
+
+/// A `DynamicUniverse` exported to JavaScript
+///
+/// `Universe<W, H>` cannot cross the `wasm-bindgen` boundary because of its
+/// const generic parameters, so the dimensions are chosen at runtime instead.
+#[wasm_bindgen]
+pub struct WasmUniverse {
+    universe: DynamicUniverse,
+}
+
+#[wasm_bindgen]
+impl WasmUniverse {
+    /// Creates an all-dead universe, throwing if either dimension is zero
+    #[wasm_bindgen(constructor)]
+    pub fn new(width: u32, height: u32) -> Result<WasmUniverse, JsError> {
+        let universe = DynamicUniverse::new(width as usize, height as usize)?;
+        Ok(WasmUniverse { universe })
+    }
+
+    /// Returns the number of columns
+    pub fn width(&self) -> u32 {
+        self.universe.width() as u32
+    }
+
+    /// Returns the number of rows
+    pub fn height(&self) -> u32 {
+        self.universe.height() as u32
+    }
+
+    /// Evolves the universe
+    pub fn evolve(&mut self) {
+        self.universe.evolve();
+    }
+
+    /// Sets the cell alive or dead
+    pub fn set_cell(&mut self, row: u32, col: u32, alive: bool) {
+        let state = if alive { State::Alive } else { State::Dead };
+        self.universe.set_cell(row as usize, col as usize, state);
+    }
+
+    /// Returns true if the cell is alive
+    pub fn is_alive(&self, row: u32, col: u32) -> bool {
+        self.universe.is_alive(row as usize, col as usize)
+    }
+
+    /// Returns the number of times the universe has evolved, wrapping
+    /// around at `u32::MAX`
+    pub fn generation(&self) -> u32 {
+        self.universe.generation() as u32
+    }
+
+    /// Returns a pointer to the `width * height` cell bytes in wasm memory
+    ///
+    /// Cells are row-major, `0` for dead and `1` for alive. The pointer is
+    /// invalidated by the next `evolve()`.
+    pub fn cells_ptr(&self) -> *const u8 {
+        self.universe.cells().as_ptr()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wasm_universe() {
+        let mut universe = WasmUniverse::new(5, 5).unwrap();
+        assert_eq!((universe.width(), universe.height()), (5, 5));
+        for col in 1..4 {
+            universe.set_cell(2, col, true);
+        }
+        universe.evolve();
+        assert_eq!(universe.generation(), 1);
+        assert!(universe.is_alive(1, 2) && universe.is_alive(3, 2));
+        assert!(!universe.is_alive(2, 1));
+    }
+
+    #[test]
+    fn test_cells_ptr() {
+        let mut universe = WasmUniverse::new(3, 2).unwrap();
+        universe.set_cell(1, 2, true);
+        // SAFETY: the buffer holds width * height bytes and is not modified
+        // while the slice is alive
+        let cells = unsafe { core::slice::from_raw_parts(universe.cells_ptr(), 6) };
+        assert_eq!(cells, [0, 0, 0, 0, 0, 1]);
+    }
+}
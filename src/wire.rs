@@ -0,0 +1,244 @@
+use modular_bitfield::prelude::*;
+
+use crate::{check_bounds, BoundaryCondition};
+
+/// The state of a cell in Wireworld
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, BitfieldSpecifier)]
+#[bits = 2]
+pub enum WireState {
+    #[default]
+    Empty = 0,
+    Conductor = 1,
+    ElectronHead = 2,
+    ElectronTail = 3,
+}
+
+/// Cell of a Wireworld universe
+#[bitfield(bits = 8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WireCell {
+    state: WireState,
+    head_neighbors: B6,
+}
+
+impl WireCell {
+    /// Sets a new state for the cell based on the current state and electron
+    /// head neighbors
+    fn evolve(&mut self) {
+        let state = match (self.state(), self.head_neighbors()) {
+            (WireState::Empty, _) => WireState::Empty,
+            (WireState::ElectronHead, _) => WireState::ElectronTail,
+            (WireState::ElectronTail, _) => WireState::Conductor,
+            (WireState::Conductor, 1 | 2) => WireState::ElectronHead,
+            (WireState::Conductor, _) => WireState::Conductor,
+        };
+        self.set_state(state);
+    }
+
+    /// Returns true if the cell is an electron head
+    pub fn is_head(&self) -> bool {
+        self.state() == WireState::ElectronHead
+    }
+}
+
+impl Default for WireCell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A Wireworld universe with a fixed width and height
+///
+/// Electron heads become tails, tails become conductors and conductors become
+/// heads when one or two of their Moore neighbors are heads. The grid wraps
+/// around its edges.
+pub struct WireUniverse<const W: usize, const H: usize> {
+    grid: [[WireCell; W]; H],
+    grid_back: [[WireCell; W]; H],
+}
+
+impl<const W: usize, const H: usize> WireUniverse<W, H> {
+    /// A diode that lets electrons entering at its left end, row 1, leave at its
+    /// right end and blocks electrons travelling the other way
+    #[rustfmt::skip]
+    pub const DIODE: [[WireState; 6]; 3] = {
+        const E: WireState = WireState::Empty;
+        const C: WireState = WireState::Conductor;
+        [
+            [E, C, C, E, E, E],
+            [C, C, E, C, C, C],
+            [E, C, C, E, E, E],
+        ]
+    };
+
+    /// Creates a universe where every cell is empty
+    pub const fn new() -> Self {
+        WireUniverse {
+            grid: [[WireCell::new(); W]; H],
+            grid_back: [[WireCell::new(); W]; H],
+        }
+    }
+
+    /// Returns the reference to the grid
+    pub fn grid(&self) -> &[[WireCell; W]; H] {
+        &self.grid
+    }
+
+    /// Sets the state of the cell
+    ///
+    /// Panics if the cell is outside the grid.
+    pub fn set_cell(&mut self, row: usize, column: usize, state: WireState) {
+        check_bounds::<W, H>(row, column);
+        self.grid[row][column].set_state(state);
+    }
+
+    /// Returns the state of the cell
+    ///
+    /// Panics if the cell is outside the grid.
+    pub fn state(&self, row: usize, column: usize) -> WireState {
+        check_bounds::<W, H>(row, column);
+        self.grid[row][column].state()
+    }
+
+    /// Places [`DIODE`](Self::DIODE) with its top-left corner at the given position,
+    /// wrapping around the edges
+    pub fn seed_diode(&mut self, row: usize, column: usize) {
+        for (delta_row, states) in Self::DIODE.iter().enumerate() {
+            for (delta_col, &state) in states.iter().enumerate() {
+                self.set_cell((row + delta_row) % H, (column + delta_col) % W, state);
+            }
+        }
+    }
+
+    /// Evolves the universe
+    pub fn evolve(&mut self) {
+        for row in 0..H {
+            for column in 0..W {
+                let mut cell = self.grid[row][column];
+                cell.set_head_neighbors(self.head_neighbor_count(row, column));
+                cell.evolve();
+                self.grid_back[row][column] = cell;
+            }
+        }
+
+        core::mem::swap(&mut self.grid, &mut self.grid_back);
+    }
+
+    /// Counts the electron head neighbors of a cell, wrapping around the edges
+    fn head_neighbor_count(&self, row: usize, column: usize) -> u8 {
+        let mut count = 0;
+        BoundaryCondition::Toroidal.for_each_moore_neighbor(row, column, H, W, |row, column| {
+            count += self.grid[row][column].is_head() as u8;
+        });
+        count
+    }
+}
+
+impl<const W: usize, const H: usize> Default for WireUniverse<W, H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn states<const W: usize, const H: usize>(
+        universe: &WireUniverse<W, H>,
+    ) -> [[WireState; W]; H] {
+        let mut states = [[WireState::Empty; W]; H];
+        for (row, cells) in universe.grid().iter().enumerate() {
+            for (column, cell) in cells.iter().enumerate() {
+                states[row][column] = cell.state();
+            }
+        }
+        states
+    }
+
+    /// A wire along row 2 with a diode at columns 6 to 11, carrying one
+    /// electron towards the diode from the left or from the right
+    fn diode_circuit(forward: bool) -> WireUniverse<20, 5> {
+        let mut universe = WireUniverse::new();
+        for column in 1..19 {
+            universe.set_cell(2, column, WireState::Conductor);
+        }
+        universe.seed_diode(1, 6);
+        let (tail, head) = if forward { (1, 2) } else { (18, 17) };
+        universe.set_cell(2, tail, WireState::ElectronTail);
+        universe.set_cell(2, head, WireState::ElectronHead);
+        universe
+    }
+
+    #[test]
+    fn test_head_becomes_tail_becomes_conductor() {
+        let mut universe = WireUniverse::<5, 5>::new();
+        universe.set_cell(2, 2, WireState::ElectronHead);
+        universe.evolve();
+        assert_eq!(universe.state(2, 2), WireState::ElectronTail);
+        universe.evolve();
+        assert_eq!(universe.state(2, 2), WireState::Conductor);
+        assert_eq!(universe.state(0, 0), WireState::Empty);
+    }
+
+    #[test]
+    fn test_conductor_fires_with_one_or_two_heads() {
+        let mut universe = WireUniverse::<5, 5>::new();
+        universe.set_cell(2, 2, WireState::Conductor);
+        universe.set_cell(1, 1, WireState::ElectronHead);
+        universe.evolve();
+        assert_eq!(universe.state(2, 2), WireState::ElectronHead);
+
+        let mut universe = WireUniverse::<5, 5>::new();
+        universe.set_cell(2, 2, WireState::Conductor);
+        for column in 1..4 {
+            universe.set_cell(1, column, WireState::ElectronHead);
+        }
+        universe.evolve();
+        assert_eq!(universe.state(2, 2), WireState::Conductor);
+    }
+
+    #[test]
+    fn test_diode_passes_electrons_one_way() {
+        let passes = |forward: bool, watched: usize| {
+            let mut universe = diode_circuit(forward);
+            (0..30).any(|_| {
+                universe.evolve();
+                universe.state(2, watched) == WireState::ElectronHead
+            })
+        };
+        assert!(passes(true, 16));
+        assert!(!passes(false, 3));
+    }
+
+    #[test]
+    fn test_clock_signal_circulates() {
+        // A loop of 12 conductors with its corners cut, so the electron
+        // follows a single path
+        let mut universe = WireUniverse::<8, 6>::new();
+        for column in 2..6 {
+            universe.set_cell(1, column, WireState::Conductor);
+            universe.set_cell(4, column, WireState::Conductor);
+        }
+        for row in 2..4 {
+            universe.set_cell(row, 1, WireState::Conductor);
+            universe.set_cell(row, 6, WireState::Conductor);
+        }
+        universe.set_cell(1, 2, WireState::ElectronTail);
+        universe.set_cell(1, 3, WireState::ElectronHead);
+        let initial = states(&universe);
+
+        for generation in 1..=12 {
+            universe.evolve();
+            let heads = universe.grid().iter().flatten().filter(|c| c.is_head());
+            assert_eq!(heads.count(), 1);
+            assert_eq!(states(&universe) == initial, generation == 12);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cell (3, 1) is out of bounds for a 4x3 universe")]
+    fn test_set_cell_out_of_bounds() {
+        WireUniverse::<4, 3>::new().set_cell(3, 1, WireState::Conductor);
+    }
+}
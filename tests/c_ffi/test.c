@@ -0,0 +1,50 @@
+/*
+ * Integration test for the C API. Build and run from the repository root:
+ *
+ *   cargo rustc --release --features c-ffi --crate-type staticlib
+ *   cc -std=c99 -Wall -Iinclude tests/c_ffi/test.c \
+ *       target/release/libgame_of_life_core.a -lpthread -ldl -lm -o target/c_ffi_test
+ *   ./target/c_ffi_test
+ */
+#include <assert.h>
+#include <stdio.h>
+
+#include "game_of_life_core.h"
+
+int main(void) {
+    assert(universe_new(0, 5) == NULL);
+
+    OpaqueUniverse *u = universe_new(5, 5);
+    assert(u != NULL);
+
+    /* Horizontal blinker through the center */
+    for (uint32_t col = 1; col < 4; col++) {
+        universe_set_cell(u, 2, col, true);
+    }
+    universe_evolve(u);
+    assert(universe_generation(u) == 1);
+    assert(universe_is_alive(u, 1, 2));
+    assert(universe_is_alive(u, 2, 2));
+    assert(universe_is_alive(u, 3, 2));
+    assert(!universe_is_alive(u, 2, 1));
+
+    uint8_t small[4];
+    assert(universe_copy_to_buffer(u, small, sizeof small) == -1);
+
+    uint8_t cells[25];
+    assert(universe_copy_to_buffer(u, cells, sizeof cells) == 0);
+    int alive = 0;
+    for (size_t i = 0; i < sizeof cells; i++) {
+        alive += cells[i];
+    }
+    assert(alive == 3);
+
+    universe_evolve(u);
+    assert(universe_generation(u) == 2);
+    assert(universe_is_alive(u, 2, 1));
+
+    universe_free(u);
+    universe_free(NULL);
+    puts("c_ffi: ok");
+    return 0;
+}